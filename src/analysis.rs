@@ -0,0 +1,125 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, Channel, Directions, ElementIDT, LoadT, ManycoreSystem,
+    RoutingResult, RoutingType, WithID,
+};
+
+pub use self::algorithm_comparison::*;
+pub use self::bottleneck::*;
+pub use self::comparison::*;
+pub use self::energy::*;
+pub use self::inference::*;
+pub use self::latency::*;
+pub use self::load_balance::*;
+pub use self::matrices::*;
+pub use self::queueing::*;
+pub use self::router_loads::*;
+pub use self::traversal::*;
+pub use self::utilisation::*;
+
+mod algorithm_comparison;
+mod bottleneck;
+mod comparison;
+mod energy;
+mod inference;
+mod latency;
+mod load_balance;
+mod matrices;
+mod queueing;
+mod router_loads;
+mod traversal;
+mod utilisation;
+
+/// Observed and computed load on a channel or source port.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct LoadResidual {
+    /// The ID of the core the channel or source port belongs to.
+    core_id: ElementIDT,
+    /// The channel or source port direction.
+    direction: Directions,
+    /// Whether this is an output channel or a source channel.
+    routing_type: RoutingType,
+    /// The observed load.
+    observed: LoadT,
+    /// The computed load.
+    computed: LoadT,
+}
+
+impl LoadResidual {
+    /// Computed load minus observed load.
+    pub fn residual(&self) -> i128 {
+        i128::from(self.computed) - i128::from(self.observed)
+    }
+
+    /// Absolute difference between computed and observed load.
+    pub fn absolute_residual(&self) -> LoadT {
+        self.computed.abs_diff(self.observed)
+    }
+
+    /// Absolute residual relative to the observed load. [`None`] if no load was observed.
+    pub fn relative_residual(&self) -> Option<f64> {
+        (self.observed != 0).then(|| self.absolute_residual() as f64 / self.observed as f64)
+    }
+}
+
+impl ManycoreSystem {
+    /// Returns the channel leaving the given core in the given direction.
+    pub(crate) fn channel(
+        &self,
+        core_id: ElementIDT,
+        direction: Directions,
+    ) -> Result<&Channel, ManycoreError> {
+        self.cores
+            .list()
+            .get(usize::from(core_id))
+            .and_then(|core| core.channels().channel().get(&direction))
+            .ok_or(routing_error(format!(
+                "Core with ID {core_id} has no {direction} channel."
+            )))
+    }
+
+    /// Pairs observed and computed loads on every channel, and on every source port whose
+    /// traffic was recorded in the XML.
+    pub(crate) fn load_residuals(
+        &self,
+        observed: &RoutingResult,
+        computed: &RoutingResult,
+    ) -> Result<Vec<LoadResidual>, ManycoreError> {
+        let mut residuals = Vec::new();
+
+        for core in self.cores.list() {
+            for direction in core.channels().channel().keys() {
+                residuals.push(LoadResidual {
+                    core_id: *core.id(),
+                    direction: *direction,
+                    routing_type: RoutingType::OutputChannel,
+                    observed: observed.channel_load(*core.id(), *direction),
+                    computed: computed.channel_load(*core.id(), *direction),
+                });
+            }
+        }
+
+        if let Some(borders) = self.borders.as_ref() {
+            for source in borders.sources().values() {
+                if source.actual_com_cost().is_some() {
+                    let core_id = ElementIDT::try_from(*source.core_id())?;
+                    let direction = Directions::from(source.direction());
+
+                    residuals.push(LoadResidual {
+                        core_id,
+                        direction,
+                        routing_type: RoutingType::SourceChannel,
+                        observed: observed.source_load(core_id, direction),
+                        computed: computed.source_load(core_id, direction),
+                    });
+                }
+            }
+        }
+
+        Ok(residuals)
+    }
+}
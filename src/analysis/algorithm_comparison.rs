@@ -0,0 +1,131 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, ChannelIDT, LatencyModel, LoadBalance, LoadT, ManycoreSystem,
+    RoutingAlgorithms, RoutingResult, SkippedAlgorithm, WithID,
+};
+
+#[cfg(doc)]
+use crate::ROUTING_ALGORITHMS;
+
+/// Metrics of a single algorithm in an [`AlgorithmComparison`].
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct AlgorithmMetrics {
+    /// The evaluated algorithm.
+    algorithm: RoutingAlgorithms,
+    /// Load balance metrics. No hottest channels are listed, see `loads` instead.
+    load_balance: LoadBalance,
+    /// Average edge latency. [`None`] if the algorithm does not route individual edges.
+    average_latency: Option<f64>,
+    /// Highest edge latency. [`None`] if the algorithm does not route individual edges.
+    worst_case_latency: Option<f64>,
+    /// Highest channel utilisation.
+    max_utilisation: f64,
+    /// Average channel utilisation.
+    average_utilisation: f64,
+    /// Number of channels whose load exceeds their bandwidth.
+    over_subscribed: usize,
+    /// Channel loads, aligned with [`AlgorithmComparison::channels`].
+    loads: Vec<LoadT>,
+}
+
+/// Side by side metrics of several routing algorithms on the same system.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct AlgorithmComparison {
+    /// Every channel, ordered by core ID and direction.
+    channels: Vec<ChannelIDT>,
+    /// Metrics in the order algorithms were requested.
+    algorithms: Vec<AlgorithmMetrics>,
+    /// Algorithms that could not be routed or evaluated on this system.
+    skipped: Vec<SkippedAlgorithm>,
+}
+
+impl ManycoreSystem {
+    /// Works out the metrics of `algorithm` from the loads it routed in `result`.
+    fn algorithm_metrics(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        result: &RoutingResult,
+        channels: &[ChannelIDT],
+        model: &LatencyModel,
+    ) -> Result<AlgorithmMetrics, ManycoreError> {
+        let utilisation = self.result_utilisation(result);
+        let (average_latency, worst_case_latency) = if result.edge_routes().routes().is_empty() {
+            (None, None)
+        } else {
+            let latency = self.estimate_latency(result, model)?;
+            (
+                Some(*latency.average_latency()),
+                Some(*latency.worst_case_latency()),
+            )
+        };
+
+        Ok(AlgorithmMetrics {
+            algorithm: algorithm.clone(),
+            load_balance: self.load_balance(result, 0)?,
+            average_latency,
+            worst_case_latency,
+            max_utilisation: *utilisation.max_utilisation(),
+            average_utilisation: *utilisation.average_utilisation(),
+            over_subscribed: utilisation.over_subscribed().len(),
+            loads: channels
+                .iter()
+                .map(|(core_id, direction)| result.channel_load(*core_id, *direction))
+                .collect(),
+        })
+    }
+
+    /// Evaluates each of `algorithms` on the system without modifying it. Algorithms that
+    /// cannot route the system, or whose metrics cannot be worked out, are listed as skipped.
+    pub fn compare_algorithms(
+        &self,
+        algorithms: &[RoutingAlgorithms],
+        model: &LatencyModel,
+    ) -> Result<AlgorithmComparison, ManycoreError> {
+        let channels = self
+            .cores
+            .list()
+            .iter()
+            .flat_map(|core| {
+                core.channels()
+                    .channel()
+                    .keys()
+                    .map(|direction| (*core.id(), *direction))
+            })
+            .collect::<Vec<ChannelIDT>>();
+
+        let mut metrics = Vec::with_capacity(algorithms.len());
+        let mut skipped = Vec::new();
+        for algorithm in algorithms {
+            match self
+                .compute_routing(algorithm)
+                .and_then(|result| self.algorithm_metrics(algorithm, &result, &channels, model))
+            {
+                Ok(algorithm_metrics) => metrics.push(algorithm_metrics),
+                Err(e) => skipped.push(SkippedAlgorithm::new(algorithm.clone(), e.to_string())),
+            }
+        }
+
+        Ok(AlgorithmComparison {
+            channels,
+            algorithms: metrics,
+            skipped,
+        })
+    }
+
+    /// Evaluates every algorithm in [`ROUTING_ALGORITHMS`] on the system without modifying
+    /// it, randomised ones once per seed in `seeds`. Algorithms that cannot route the system,
+    /// or whose metrics cannot be worked out, are listed as skipped.
+    pub fn compare_all_algorithms(
+        &self,
+        model: &LatencyModel,
+        seeds: &[u64],
+    ) -> Result<AlgorithmComparison, ManycoreError> {
+        self.compare_algorithms(&RoutingAlgorithms::all(seeds), model)
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, ChannelIDT, EdgeRoute, LoadT, ManycoreSystem,
+    RoutingAlgorithms, RoutingHop, RoutingResult, RoutingType,
+};
+
+/// Load a task graph edge puts on a channel or source port.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct EdgeContribution {
+    /// Index of the edge in the task graph.
+    edge_index: usize,
+    /// Edge source task.
+    from: u16,
+    /// Edge destination task.
+    to: u16,
+    /// Load the edge contributes.
+    load: LoadT,
+    /// Fraction of the hop's load contributed by the edge.
+    share: f64,
+}
+
+/// The task graph edges making up the load of a channel or source port.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct LoadAttribution {
+    /// The attributed channel or source port.
+    hop: RoutingHop,
+    /// Total load on the hop.
+    load: LoadT,
+    /// Contributing edges, from largest to smallest contribution.
+    contributions: Vec<EdgeContribution>,
+}
+
+/// A single task reallocation lowering the most loaded channel.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct ReallocationSuggestion {
+    /// The task to move.
+    task_id: u16,
+    /// The core currently hosting the task.
+    from_core: usize,
+    /// The free core to move the task onto.
+    to_core: usize,
+    /// Highest channel load with the current allocation.
+    peak_before: LoadT,
+    /// Highest channel load once the task is moved.
+    peak_after: LoadT,
+}
+
+/// Adds or removes the load a route puts on each channel.
+fn update_channel_loads(
+    loads: &mut BTreeMap<ChannelIDT, LoadT>,
+    route: &EdgeRoute,
+    add: bool,
+) -> Result<(), ManycoreError> {
+    for hop in route
+        .hops()
+        .iter()
+        .filter(|hop| *hop.routing_type() == RoutingType::OutputChannel)
+    {
+        let load = loads.entry((*hop.core_id(), *hop.direction())).or_default();
+        *load = if add {
+            load.checked_add(*route.communication_cost())
+        } else {
+            load.checked_sub(*route.communication_cost())
+        }
+        .ok_or(routing_error(format!(
+            "Load on the {} channel of Core with ID {} overflowed.",
+            hop.direction(),
+            hop.core_id()
+        )))?;
+    }
+
+    Ok(())
+}
+
+impl ManycoreSystem {
+    /// Lists the task graph edges routed over `hop` in `result`, along with their share of
+    /// its load.
+    pub fn attribute_load(
+        &self,
+        result: &RoutingResult,
+        hop: &RoutingHop,
+    ) -> Result<LoadAttribution, ManycoreError> {
+        if *result.algorithm() == RoutingAlgorithms::Observed {
+            return Err(routing_error(
+                "Observed loads are not routed edge by edge and cannot be attributed.".into(),
+            ));
+        }
+
+        let overflow = || {
+            routing_error(format!(
+                "Load on the {} hop of Core with ID {} overflowed.",
+                hop.direction(),
+                hop.core_id()
+            ))
+        };
+
+        let mut contributions = result
+            .edge_routes()
+            .traversing(hop)
+            .into_iter()
+            .map(|route| {
+                // An edge pays for every time it traverses the hop.
+                let traversals = route.hops().iter().filter(|h| *h == hop).count();
+
+                Ok(EdgeContribution {
+                    edge_index: *route.edge_index(),
+                    from: *route.from(),
+                    to: *route.to(),
+                    load: route
+                        .communication_cost()
+                        .checked_mul(LoadT::try_from(traversals)?)
+                        .ok_or_else(overflow)?,
+                    share: 0.0,
+                })
+            })
+            .collect::<Result<Vec<EdgeContribution>, ManycoreError>>()?;
+
+        let load = contributions
+            .iter()
+            .try_fold(0, |total: LoadT, c| total.checked_add(c.load))
+            .ok_or_else(overflow)?;
+        for contribution in &mut contributions {
+            contribution.share = if load == 0 {
+                0.0
+            } else {
+                contribution.load as f64 / load as f64
+            };
+        }
+        // Stable sort, ties keep task graph edge order.
+        contributions.sort_by_key(|c| std::cmp::Reverse(c.load));
+
+        Ok(LoadAttribution {
+            hop: *hop,
+            load,
+            contributions,
+        })
+    }
+
+    /// Tries moving every allocated task onto every free core and returns the move that
+    /// lowers the highest channel load under `algorithm` the most, or [`None`] if no single
+    /// move lowers it. Moves the algorithm cannot route are not considered.
+    pub fn suggest_reallocation(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<Option<ReallocationSuggestion>, ManycoreError> {
+        if *algorithm == RoutingAlgorithms::Observed {
+            return Err(routing_error(
+                "Observed loads do not depend on the task allocation.".into(),
+            ));
+        }
+
+        let result = self.compute_routing(algorithm)?;
+        let peak_before = result
+            .channel_loads()
+            .values()
+            .copied()
+            .max()
+            .unwrap_or_default();
+
+        let free_cores = self
+            .cores
+            .list()
+            .iter()
+            .enumerate()
+            .filter(|(_, core)| core.allocated_task().is_none())
+            .map(|(core_id, _)| core_id)
+            .collect::<Vec<usize>>();
+        let allocations = self
+            .task_core_map
+            .iter()
+            .map(|(task_id, core_id)| (*task_id, *core_id))
+            .collect::<BTreeSet<(u16, usize)>>();
+
+        // Candidate allocations are routed without editing the system.
+        let mut allocation = self.task_core_map.clone();
+        let mut best: Option<ReallocationSuggestion> = None;
+        for (task_id, from_core) in allocations {
+            let affected = self
+                .task_graph
+                .edges()
+                .iter()
+                .enumerate()
+                .filter(|(_, edge)| *edge.from() == task_id || *edge.to() == task_id)
+                .map(|(edge_index, _)| edge_index)
+                .collect::<Vec<usize>>();
+
+            for to_core in &free_cores {
+                allocation.insert(task_id, *to_core);
+                let routes = affected
+                    .iter()
+                    .map(|edge_index| {
+                        self.allocated_edge_route(algorithm, *edge_index, &allocation)
+                    })
+                    .collect::<Result<Vec<EdgeRoute>, ManycoreError>>();
+                allocation.insert(task_id, from_core);
+
+                let Ok(routes) = routes else {
+                    continue;
+                };
+
+                let mut loads = result.channel_loads().clone();
+                for route in result
+                    .edge_routes()
+                    .routes()
+                    .iter()
+                    .filter(|route| affected.contains(route.edge_index()))
+                {
+                    update_channel_loads(&mut loads, route, false)?;
+                }
+                for route in &routes {
+                    update_channel_loads(&mut loads, route, true)?;
+                }
+
+                let peak_after = loads.values().copied().max().unwrap_or_default();
+                if peak_after < best.as_ref().map_or(peak_before, |b| b.peak_after) {
+                    best = Some(ReallocationSuggestion {
+                        task_id,
+                        from_core,
+                        to_core: *to_core,
+                        peak_before,
+                        peak_after,
+                    });
+                }
+            }
+        }
+
+        Ok(best)
+    }
+}
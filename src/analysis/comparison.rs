@@ -0,0 +1,94 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, LoadResidual, LoadT, ManycoreSystem, RoutingResult,
+};
+
+/// Summary statistics of an observed versus computed load comparison.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct ComparisonSummary {
+    /// Number of compared channels and source ports.
+    compared: usize,
+    /// Number of channels and source ports whose loads differ.
+    mismatches: usize,
+    /// Sum of the observed loads.
+    total_observed: LoadT,
+    /// Sum of the computed loads.
+    total_computed: LoadT,
+    /// Sum of the absolute residuals.
+    total_absolute_residual: LoadT,
+    /// Largest absolute residual.
+    max_absolute_residual: LoadT,
+    /// Mean absolute residual.
+    mean_absolute_residual: f64,
+    /// Mean relative residual over the channels and source ports with observed load.
+    /// [`None`] if no load was observed.
+    mean_relative_residual: Option<f64>,
+}
+
+/// Observed versus computed loads on every channel and recorded source port.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct LoadComparison {
+    /// Residuals from largest to smallest absolute residual.
+    residuals: Vec<LoadResidual>,
+    /// Summary statistics.
+    summary: ComparisonSummary,
+}
+
+impl ManycoreSystem {
+    /// Compares the loads of an observed routing with those of a computed one, e.g. the
+    /// results of [`RoutingAlgorithms::Observed`] and [`RoutingAlgorithms::RowFirst`].
+    ///
+    /// [`RoutingAlgorithms::Observed`]: crate::RoutingAlgorithms::Observed
+    /// [`RoutingAlgorithms::RowFirst`]: crate::RoutingAlgorithms::RowFirst
+    pub fn compare_loads(
+        &self,
+        observed: &RoutingResult,
+        computed: &RoutingResult,
+    ) -> Result<LoadComparison, ManycoreError> {
+        let mut residuals = self.load_residuals(observed, computed)?;
+        // Stable sort, ties keep core and direction order.
+        residuals.sort_by_key(|r| std::cmp::Reverse(r.absolute_residual()));
+
+        let sum = |value: fn(&LoadResidual) -> LoadT, what: &str| {
+            residuals
+                .iter()
+                .try_fold(0, |total: LoadT, r| total.checked_add(value(r)))
+                .ok_or(routing_error(format!("Total {what} overflowed.")))
+        };
+        let total_observed = sum(|r| r.observed, "observed load")?;
+        let total_computed = sum(|r| r.computed, "computed load")?;
+        let total_absolute_residual = sum(LoadResidual::absolute_residual, "residual")?;
+
+        let relative_residuals = residuals
+            .iter()
+            .filter_map(LoadResidual::relative_residual)
+            .collect::<Vec<f64>>();
+
+        let summary = ComparisonSummary {
+            compared: residuals.len(),
+            mismatches: residuals.iter().filter(|r| r.residual() != 0).count(),
+            total_observed,
+            total_computed,
+            total_absolute_residual,
+            max_absolute_residual: residuals
+                .first()
+                .map(LoadResidual::absolute_residual)
+                .unwrap_or_default(),
+            mean_absolute_residual: if residuals.is_empty() {
+                0.0
+            } else {
+                total_absolute_residual as f64 / residuals.len() as f64
+            },
+            mean_relative_residual: (!relative_residuals.is_empty())
+                .then(|| relative_residuals.iter().sum::<f64>() / relative_residuals.len() as f64),
+        };
+
+        Ok(LoadComparison { residuals, summary })
+    }
+}
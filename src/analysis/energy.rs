@@ -0,0 +1,197 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, Directions, ElementIDT, LoadT, ManycoreSystem,
+    RoutingAlgorithms, RoutingResult, WithID,
+};
+
+/// Parameters of the energy model. Energy is expressed in whatever unit the parameters
+/// are, with power multiplied by `duration` to obtain static energy.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct EnergyModel {
+    /// Energy spent by a router for every unit of traffic it forwards.
+    router_energy: f64,
+    /// Energy spent by a link for every unit of traffic it carries.
+    link_energy: f64,
+    /// Static power drawn by every router.
+    router_static_power: f64,
+    /// Static power drawn by every core.
+    core_static_power: f64,
+    /// Time the static power is drawn for.
+    duration: f64,
+}
+
+impl EnergyModel {
+    /// Instantiates a new [`EnergyModel`] instance.
+    pub fn new(
+        router_energy: f64,
+        link_energy: f64,
+        router_static_power: f64,
+        core_static_power: f64,
+        duration: f64,
+    ) -> Self {
+        Self {
+            router_energy,
+            link_energy,
+            router_static_power,
+            core_static_power,
+            duration,
+        }
+    }
+}
+
+/// Energy spent by a single channel.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct ChannelEnergy {
+    /// The ID of the core the channel leaves.
+    core_id: ElementIDT,
+    /// The channel direction.
+    direction: Directions,
+    /// Energy spent carrying the channel's load.
+    energy: f64,
+}
+
+/// Energy spent by every element of the system.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct EnergyReport {
+    /// Static energy of every core, indexed by core ID.
+    cores: Vec<f64>,
+    /// Dynamic and static energy of every router, indexed by core ID.
+    routers: Vec<f64>,
+    /// Energy of every channel, ordered by core ID and direction.
+    channels: Vec<ChannelEnergy>,
+    /// Energy spent forwarding and carrying traffic.
+    dynamic_energy: f64,
+    /// Energy drawn by routers and cores regardless of traffic.
+    static_energy: f64,
+    /// Sum of dynamic and static energy.
+    total_energy: f64,
+}
+
+impl EnergyReport {
+    /// Returns the energy of the core with the given ID.
+    pub fn core_energy(&self, core_id: ElementIDT) -> Option<f64> {
+        self.cores.get(usize::from(core_id)).copied()
+    }
+
+    /// Returns the energy of the router of the core with the given ID.
+    pub fn router_energy(&self, core_id: ElementIDT) -> Option<f64> {
+        self.routers.get(usize::from(core_id)).copied()
+    }
+
+    /// Returns the energy of the channel leaving the given core in the given direction.
+    pub fn channel_energy(&self, core_id: ElementIDT, direction: Directions) -> Option<f64> {
+        self.channels
+            .iter()
+            .find(|c| c.core_id == core_id && c.direction == direction)
+            .map(|c| c.energy)
+    }
+}
+
+impl ManycoreSystem {
+    /// Builds an [`EnergyReport`] from the load `load_of` assigns each channel. Traffic
+    /// ejected into tasks is taken from the task graph, so loads must have been routed
+    /// from it.
+    fn energy_report(
+        &self,
+        model: &EnergyModel,
+        algorithm: Option<&RoutingAlgorithms>,
+        load_of: impl Fn(ElementIDT, Directions, LoadT) -> LoadT,
+    ) -> Result<EnergyReport, ManycoreError> {
+        if algorithm == Some(&RoutingAlgorithms::Observed) {
+            return Err(routing_error(
+                "Observed loads are not routed from the task graph, their ejected traffic is unknown."
+                    .into(),
+            ));
+        }
+
+        // Traffic ejected into each core's task also crosses its router.
+        let mut ejected: Vec<LoadT> = vec![0; self.cores.list().len()];
+        for edge in self.task_graph.edges() {
+            if let Some(core_id) = self.task_core_map.get(edge.to()) {
+                if let Some(load) = ejected.get_mut(*core_id) {
+                    *load = load
+                        .checked_add(*edge.communication_cost())
+                        .ok_or(routing_error(format!(
+                            "Load through the router of Core with ID {core_id} overflowed."
+                        )))?;
+                }
+            }
+        }
+
+        let router_static_energy = model.router_static_power * model.duration;
+        let core_static_energy = model.core_static_power * model.duration;
+
+        let mut routers = Vec::with_capacity(ejected.len());
+        let mut channels = Vec::new();
+        let mut dynamic_energy = 0.0;
+
+        for core in self.cores.list() {
+            let mut forwarded = ejected[usize::from(*core.id())] as f64;
+
+            for direction in core.channels().channel().keys() {
+                let load = load_of(
+                    *core.id(),
+                    *direction,
+                    *self.channel(*core.id(), *direction)?.current_load(),
+                ) as f64;
+                forwarded += load;
+
+                let energy = load * model.link_energy;
+                dynamic_energy += energy;
+                channels.push(ChannelEnergy {
+                    core_id: *core.id(),
+                    direction: *direction,
+                    energy,
+                });
+            }
+
+            let router_dynamic_energy = forwarded * model.router_energy;
+            dynamic_energy += router_dynamic_energy;
+            routers.push(router_dynamic_energy + router_static_energy);
+        }
+
+        let static_energy = (router_static_energy + core_static_energy) * routers.len() as f64;
+
+        Ok(EnergyReport {
+            cores: vec![core_static_energy; routers.len()],
+            routers,
+            channels,
+            dynamic_energy,
+            static_energy,
+            total_energy: dynamic_energy + static_energy,
+        })
+    }
+
+    /// Computes the energy spent by the system given the loads in `result`, leaving the
+    /// system untouched. Observed results are rejected.
+    pub fn compute_energy(
+        &self,
+        result: &RoutingResult,
+        model: &EnergyModel,
+    ) -> Result<EnergyReport, ManycoreError> {
+        self.energy_report(model, Some(result.algorithm()), |core_id, direction, _| {
+            result.channel_load(core_id, direction)
+        })
+    }
+
+    /// Computes the energy spent by the system given the loads it was last routed with and
+    /// stores it, so that per element energy can be rendered. The stored report is cleared
+    /// when the system is routed again. Observed loads are rejected.
+    pub fn apply_energy(&mut self, model: &EnergyModel) -> Result<&EnergyReport, ManycoreError> {
+        let report = self.energy_report(
+            model,
+            self.routing_algorithm.as_ref(),
+            |_, _, current_load| current_load,
+        )?;
+
+        Ok(self.energy.insert(report))
+    }
+}
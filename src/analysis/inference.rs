@@ -0,0 +1,107 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, LoadResidual, LoadT, ManycoreSystem, RoutingAlgorithms,
+};
+
+#[cfg(doc)]
+use crate::ROUTING_ALGORITHMS;
+
+/// How closely an algorithm reproduces the observed loads.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct AlgorithmMatch {
+    /// The candidate algorithm.
+    algorithm: RoutingAlgorithms,
+    /// Sum of the absolute residuals over every compared channel and source port.
+    total_absolute_residual: LoadT,
+    /// Number of channels and source ports whose computed load differs from the observed one.
+    mismatches: usize,
+    /// Per channel and source port residuals.
+    residuals: Vec<LoadResidual>,
+}
+
+/// An algorithm that could not be compared against the observed loads.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct SkippedAlgorithm {
+    /// The candidate algorithm.
+    algorithm: RoutingAlgorithms,
+    /// Why the algorithm could not be routed or evaluated.
+    reason: String,
+}
+
+/// The outcome of matching every supported algorithm against the observed loads.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct AlgorithmInference {
+    /// Candidates from best to worst match. Ties keep the order of [`RoutingAlgorithms::all`].
+    matches: Vec<AlgorithmMatch>,
+    /// Candidates that could not be routed on this system.
+    skipped: Vec<SkippedAlgorithm>,
+}
+
+impl SkippedAlgorithm {
+    /// Instantiates a new [`SkippedAlgorithm`] instance.
+    pub(crate) fn new(algorithm: RoutingAlgorithms, reason: String) -> Self {
+        Self { algorithm, reason }
+    }
+}
+
+impl AlgorithmInference {
+    /// Returns the best matching candidate, if any algorithm could be routed.
+    pub fn best(&self) -> Option<&AlgorithmMatch> {
+        self.matches.first()
+    }
+}
+
+impl ManycoreSystem {
+    /// Routes the task graph with every algorithm in [`ROUTING_ALGORITHMS`], randomised ones
+    /// once per seed in `seeds`, and compares the loads of each with the observed
+    /// `actualComCost` values, to work out which algorithm most likely produced them.
+    pub fn infer_routing_algorithm(
+        &self,
+        seeds: &[u64],
+    ) -> Result<AlgorithmInference, ManycoreError> {
+        let observed = self.compute_routing(&RoutingAlgorithms::Observed)?;
+
+        let mut matches = Vec::new();
+        let mut skipped = Vec::new();
+        for algorithm in RoutingAlgorithms::all(seeds)
+            .iter()
+            .filter(|a| **a != RoutingAlgorithms::Observed)
+        {
+            let computed = match self.compute_routing(algorithm) {
+                Ok(computed) => computed,
+                Err(e) => {
+                    skipped.push(SkippedAlgorithm::new(algorithm.clone(), e.to_string()));
+                    continue;
+                }
+            };
+
+            let residuals = self.load_residuals(&observed, &computed)?;
+            let total_absolute_residual = residuals
+                .iter()
+                .try_fold(0, |total: LoadT, r| {
+                    total.checked_add(r.absolute_residual())
+                })
+                .ok_or(routing_error("Total residual overflowed.".into()))?;
+
+            matches.push(AlgorithmMatch {
+                algorithm: algorithm.clone(),
+                total_absolute_residual,
+                mismatches: residuals.iter().filter(|r| r.residual() != 0).count(),
+                residuals,
+            });
+        }
+
+        // Stable sort, ties keep the order of RoutingAlgorithms::all.
+        matches.sort_by_key(|m| m.total_absolute_residual);
+
+        Ok(AlgorithmInference { matches, skipped })
+    }
+}
@@ -0,0 +1,156 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, EdgeRoute, ManycoreSystem, RoutingResult, RoutingType,
+};
+
+/// Parameters of the hop-count latency model. Delays share whatever time unit the
+/// parameters are expressed in, typically cycles.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct LatencyModel {
+    /// Pipeline delay of a single router traversal.
+    router_delay: f64,
+    /// Traffic units making up a flit. A flit crosses a link in `flit_size / bandwidth`.
+    flit_size: f64,
+}
+
+impl LatencyModel {
+    /// Instantiates a new [`LatencyModel`] instance.
+    pub fn new(router_delay: f64, flit_size: f64) -> Self {
+        Self {
+            router_delay,
+            flit_size,
+        }
+    }
+}
+
+/// Estimated delivery latency of a task graph edge.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct EdgeLatency {
+    /// Index of the edge in the task graph.
+    edge_index: usize,
+    /// Edge source task.
+    from: u16,
+    /// Edge destination task.
+    to: u16,
+    /// Number of links traversed, including the link into a sink.
+    hop_count: usize,
+    /// Number of routers traversed.
+    router_traversals: usize,
+    /// Total router pipeline delay.
+    router_delay: f64,
+    /// Total link traversal delay of the head flit.
+    link_delay: f64,
+    /// Time to push the edge's communication cost through the slowest traversed link.
+    serialisation_delay: f64,
+    /// Sum of router, link and serialisation delays.
+    latency: f64,
+}
+
+/// Estimated latency of every task graph edge.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct LatencyReport {
+    /// Latencies in task graph edge order.
+    edges: Vec<EdgeLatency>,
+    /// Average edge latency.
+    average_latency: f64,
+    /// Highest edge latency.
+    worst_case_latency: f64,
+}
+
+impl LatencyReport {
+    /// Returns the edge with the highest latency, if any.
+    pub fn worst_case_edge(&self) -> Option<&EdgeLatency> {
+        self.edges
+            .iter()
+            .find(|edge| edge.latency == self.worst_case_latency)
+    }
+}
+
+impl ManycoreSystem {
+    /// Estimates the latency of a single routed edge.
+    pub(crate) fn edge_latency(
+        &self,
+        route: &EdgeRoute,
+        model: &LatencyModel,
+    ) -> Result<EdgeLatency, ManycoreError> {
+        let mut hop_count = 0;
+        let mut link_delay = 0.0;
+        let mut bottleneck: Option<u16> = None;
+
+        for hop in route
+            .hops()
+            .iter()
+            .filter(|hop| *hop.routing_type() == RoutingType::OutputChannel)
+        {
+            let bandwidth = *self.channel(*hop.core_id(), *hop.direction())?.bandwidth();
+            if bandwidth == 0 {
+                return Err(routing_error(format!(
+                    "Cannot estimate latency of edge {}: the {} channel of core with ID {} has no bandwidth.",
+                    route.edge_index(),
+                    hop.direction(),
+                    hop.core_id()
+                )));
+            }
+
+            hop_count += 1;
+            link_delay += model.flit_size / f64::from(bandwidth);
+            bottleneck = Some(bottleneck.map_or(bandwidth, |b| b.min(bandwidth)));
+        }
+
+        // Every link leaves a router. Edges delivered to a core also cross its router.
+        let router_traversals =
+            hop_count + usize::from(self.task_core_map.contains_key(route.to()));
+        let router_delay = router_traversals as f64 * model.router_delay;
+        // Edges between tasks on the same core never serialise onto a link.
+        let serialisation_delay = bottleneck.map_or(0.0, |bandwidth| {
+            *route.communication_cost() as f64 / f64::from(bandwidth)
+        });
+
+        Ok(EdgeLatency {
+            edge_index: *route.edge_index(),
+            from: *route.from(),
+            to: *route.to(),
+            hop_count,
+            router_traversals,
+            router_delay,
+            link_delay,
+            serialisation_delay,
+            latency: router_delay + link_delay + serialisation_delay,
+        })
+    }
+
+    /// Estimates the delivery latency of every task graph edge routed in `result`.
+    pub fn estimate_latency(
+        &self,
+        result: &RoutingResult,
+        model: &LatencyModel,
+    ) -> Result<LatencyReport, ManycoreError> {
+        let edges = result
+            .edge_routes()
+            .routes()
+            .iter()
+            .map(|route| self.edge_latency(route, model))
+            .collect::<Result<Vec<EdgeLatency>, ManycoreError>>()?;
+
+        let average_latency = if edges.is_empty() {
+            0.0
+        } else {
+            edges.iter().map(|edge| edge.latency).sum::<f64>() / edges.len() as f64
+        };
+        let worst_case_latency = edges.iter().map(|edge| edge.latency).fold(0.0, f64::max);
+
+        Ok(LatencyReport {
+            edges,
+            average_latency,
+            worst_case_latency,
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, Directions, ElementIDT, LoadT, ManycoreSystem,
+    RoutingResult, WithID,
+};
+
+/// A channel and the load routed over it.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct ChannelLoad {
+    /// The ID of the core the channel leaves.
+    core_id: ElementIDT,
+    /// The channel direction.
+    direction: Directions,
+    /// The routed load.
+    load: LoadT,
+}
+
+/// Summary of how evenly a routing run spreads load over the channels.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct LoadBalance {
+    /// Number of channels in the system.
+    channels: usize,
+    /// Number of channels carrying any load.
+    channels_used: usize,
+    /// Highest channel load.
+    max_load: LoadT,
+    /// Mean load over every channel.
+    mean_load: f64,
+    /// Population standard deviation of the load over every channel.
+    load_std_dev: f64,
+    /// Gini coefficient of the channel loads, 0 being perfectly even.
+    gini: f64,
+    /// Sum of every edge's cost times the channels it traverses, i.e. the sum of channel loads.
+    hop_weighted_traffic: LoadT,
+    /// The most loaded channels, from most to least loaded.
+    hottest: Vec<ChannelLoad>,
+}
+
+impl ManycoreSystem {
+    /// Computes load balance metrics for the channel loads in `result`, listing the
+    /// `top_n` most loaded channels. Ties are listed in core ID and direction order.
+    /// Fails if the hop weighted traffic overflows.
+    pub fn load_balance(
+        &self,
+        result: &RoutingResult,
+        top_n: usize,
+    ) -> Result<LoadBalance, ManycoreError> {
+        let mut loads = Vec::new();
+        for core in self.cores.list() {
+            for direction in core.channels().channel().keys() {
+                loads.push(ChannelLoad {
+                    core_id: *core.id(),
+                    direction: *direction,
+                    load: result.channel_load(*core.id(), *direction),
+                });
+            }
+        }
+
+        let channels = loads.len();
+        let hop_weighted_traffic = loads
+            .iter()
+            .try_fold(0, |total: LoadT, c| total.checked_add(c.load))
+            .ok_or(routing_error("Hop weighted traffic overflowed.".into()))?;
+
+        let (mean_load, load_std_dev) = if channels == 0 {
+            (0.0, 0.0)
+        } else {
+            let mean = hop_weighted_traffic as f64 / channels as f64;
+            let variance = loads
+                .iter()
+                .map(|c| (c.load as f64 - mean).powi(2))
+                .sum::<f64>()
+                / channels as f64;
+
+            (mean, variance.sqrt())
+        };
+
+        // Stable sort, ties keep core ID and direction order.
+        loads.sort_by_key(|c| std::cmp::Reverse(c.load));
+
+        // G = sum((2i - n - 1) * x_i) / (n * sum(x)) over loads in ascending order, 1-based i.
+        let gini = if hop_weighted_traffic == 0 {
+            0.0
+        } else {
+            let n = channels as f64;
+            loads
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, c)| (2.0 * (i + 1) as f64 - n - 1.0) * c.load as f64)
+                .sum::<f64>()
+                / (n * hop_weighted_traffic as f64)
+        };
+
+        Ok(LoadBalance {
+            channels,
+            channels_used: loads.iter().filter(|c| c.load != 0).count(),
+            max_load: loads.first().map(|c| c.load).unwrap_or_default(),
+            mean_load,
+            load_std_dev,
+            gini,
+            hop_weighted_traffic,
+            hottest: loads.into_iter().take(top_n).collect(),
+        })
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, ElementIDT, LoadT, ManycoreSystem, RoutingAlgorithms,
+    WithID,
+};
+
+/// Hop distance between every pair of cores under a routing algorithm.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct HopDistanceMatrix {
+    /// The algorithm paths were computed with.
+    algorithm: RoutingAlgorithms,
+    /// Channels traversed from the row core to the column core, indexed by core ID.
+    /// [`None`] if the algorithm cannot reach the column core.
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl HopDistanceMatrix {
+    /// Returns the hop distance from `start_id` to `destination_id`, if reachable.
+    pub fn distance(&self, start_id: ElementIDT, destination_id: ElementIDT) -> Option<usize> {
+        self.distances
+            .get(usize::from(start_id))?
+            .get(usize::from(destination_id))
+            .copied()
+            .flatten()
+    }
+
+    /// Serialises the matrix into CSV, with a header row and column of core IDs.
+    /// Unreachable pairs are left empty.
+    pub fn to_csv(&self) -> String {
+        matrix_csv(
+            &(0..self.distances.len()).collect::<Vec<usize>>(),
+            &self.distances,
+            |distance| distance.map(|d| d.to_string()).unwrap_or_default(),
+        )
+    }
+}
+
+/// A row or column of a [`TrafficMatrix`].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum TrafficEndpoint {
+    /// The core with the given ID.
+    Core(ElementIDT),
+    /// The source of the task with the given ID.
+    Source(u16),
+    /// The sink of the task with the given ID.
+    Sink(u16),
+}
+
+impl Display for TrafficEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrafficEndpoint::Core(core_id) => write!(f, "{core_id}"),
+            TrafficEndpoint::Source(task_id) => write!(f, "source {task_id}"),
+            TrafficEndpoint::Sink(task_id) => write!(f, "sink {task_id}"),
+        }
+    }
+}
+
+/// Task graph traffic aggregated between cores, sources and sinks.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct TrafficMatrix {
+    /// Row and column endpoints: cores by ID, then sources and sinks by task ID.
+    endpoints: Vec<TrafficEndpoint>,
+    /// Traffic from the row endpoint to the column endpoint.
+    traffic: Vec<Vec<LoadT>>,
+}
+
+impl TrafficMatrix {
+    /// Returns the traffic from `from` to `to`, or [`None`] if either is not an endpoint.
+    pub fn traffic_between(&self, from: &TrafficEndpoint, to: &TrafficEndpoint) -> Option<LoadT> {
+        let row = self.endpoints.iter().position(|e| e == from)?;
+        let column = self.endpoints.iter().position(|e| e == to)?;
+
+        Some(self.traffic[row][column])
+    }
+
+    /// Serialises the matrix into CSV, with a header row and column of endpoints.
+    pub fn to_csv(&self) -> String {
+        matrix_csv(&self.endpoints, &self.traffic, |load| load.to_string())
+    }
+}
+
+/// Serialises a square matrix into CSV, labelling rows and columns.
+fn matrix_csv<L: Display, T>(labels: &[L], rows: &[Vec<T>], cell: impl Fn(&T) -> String) -> String {
+    let mut csv = String::new();
+
+    for label in labels {
+        csv.push_str(&format!(",{label}"));
+    }
+    csv.push('\n');
+
+    for (label, row) in labels.iter().zip(rows) {
+        csv.push_str(&label.to_string());
+        for value in row {
+            csv.push_str(&format!(",{}", cell(value)));
+        }
+        csv.push('\n');
+    }
+
+    csv
+}
+
+impl ManycoreSystem {
+    /// Computes the number of channels traversed between every pair of cores under the
+    /// given algorithm. Pairs the algorithm cannot connect, such as those partial routing
+    /// tables have no entry for, are left [`None`].
+    pub fn hop_distance_matrix(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<HopDistanceMatrix, ManycoreError> {
+        if *algorithm == RoutingAlgorithms::Observed {
+            return Err(routing_error(
+                "Observed loads do not define paths between cores.".into(),
+            ));
+        }
+
+        let core_ids: Vec<ElementIDT> = self.cores.list().iter().map(|core| *core.id()).collect();
+
+        let distances = core_ids
+            .iter()
+            .map(|start_id| {
+                core_ids
+                    .iter()
+                    .map(|destination_id| {
+                        Ok(self
+                            .core_to_core_channel_path(algorithm, *start_id, *destination_id)?
+                            .map(|path| path.len()))
+                    })
+                    .collect::<Result<Vec<Option<usize>>, ManycoreError>>()
+            })
+            .collect::<Result<Vec<Vec<Option<usize>>>, ManycoreError>>()?;
+
+        Ok(HopDistanceMatrix {
+            algorithm: algorithm.clone(),
+            distances,
+        })
+    }
+
+    /// Aggregates the communication cost of every task graph edge by the cores, sources and
+    /// sinks its tasks are allocated on.
+    pub fn traffic_matrix(&self) -> Result<TrafficMatrix, ManycoreError> {
+        let mut endpoints: Vec<TrafficEndpoint> = self
+            .cores
+            .list()
+            .iter()
+            .map(|core| TrafficEndpoint::Core(*core.id()))
+            .collect();
+        if let Some(borders) = self.borders.as_ref() {
+            endpoints.extend(
+                borders
+                    .sources()
+                    .keys()
+                    .map(|t| TrafficEndpoint::Source(*t)),
+            );
+            endpoints.extend(borders.sinks().keys().map(|t| TrafficEndpoint::Sink(*t)));
+        }
+
+        let endpoint_index = |task_id: u16, sink: bool| -> Result<usize, ManycoreError> {
+            let endpoint = match self.task_core_map.get(&task_id) {
+                Some(core_id) => TrafficEndpoint::Core(ElementIDT::try_from(*core_id)?),
+                None if sink => TrafficEndpoint::Sink(task_id),
+                None => TrafficEndpoint::Source(task_id),
+            };
+
+            endpoints
+                .iter()
+                .position(|e| *e == endpoint)
+                .ok_or(routing_error(format!(
+                "Malformed TaskGraph: Task {task_id} is not allocated on any core, sink or source."
+            )))
+        };
+
+        let mut traffic: Vec<Vec<LoadT>> = vec![vec![0; endpoints.len()]; endpoints.len()];
+        for edge in self.task_graph.edges() {
+            let cell = &mut traffic[endpoint_index(*edge.from(), false)?]
+                [endpoint_index(*edge.to(), true)?];
+
+            *cell = cell
+                .checked_add(*edge.communication_cost())
+                .ok_or(routing_error(format!(
+                    "Traffic from task {} to task {} overflowed.",
+                    edge.from(),
+                    edge.to()
+                )))?;
+        }
+
+        Ok(TrafficMatrix { endpoints, traffic })
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, ChannelIDT, Directions, ElementIDT, LatencyModel, ManycoreSystem,
+    RoutingResult, RoutingType,
+};
+
+/// A channel modelled as an M/D/1 queue.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct ChannelQueue {
+    /// The ID of the core the channel leaves.
+    core_id: ElementIDT,
+    /// The channel direction.
+    direction: Directions,
+    /// Ratio of arrival rate (load) to service rate (bandwidth). [`None`] if the channel
+    /// has no bandwidth.
+    utilisation: Option<f64>,
+    /// Mean time a flit waits before crossing the channel. [`None`] if saturated.
+    waiting_time: Option<f64>,
+}
+
+impl ChannelQueue {
+    /// Whether arrivals meet or exceed what the channel can serve, so that its queue grows
+    /// without bound.
+    pub fn is_saturated(&self) -> bool {
+        self.waiting_time.is_none()
+    }
+}
+
+/// Delivery latency of a task graph edge including contention.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct EdgeContentionLatency {
+    /// Index of the edge in the task graph.
+    edge_index: usize,
+    /// Edge source task.
+    from: u16,
+    /// Edge destination task.
+    to: u16,
+    /// Latency without contention, see [`LatencyModel`].
+    zero_load_latency: f64,
+    /// Sum of the waiting times of traversed channels. [`None`] if any is saturated.
+    waiting_time: Option<f64>,
+    /// Zero load latency plus waiting time. [`None`] if any traversed channel is saturated.
+    latency: Option<f64>,
+}
+
+/// Outcome of the queueing contention model.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct QueueingReport {
+    /// Every channel, ordered by core ID and direction.
+    channels: Vec<ChannelQueue>,
+    /// Edges in task graph edge order.
+    edges: Vec<EdgeContentionLatency>,
+}
+
+impl QueueingReport {
+    /// Returns the channels whose utilisation is at or above 1.
+    pub fn saturated(&self) -> Vec<&ChannelQueue> {
+        self.channels.iter().filter(|c| c.is_saturated()).collect()
+    }
+}
+
+impl ManycoreSystem {
+    /// Predicts contention with every channel modelled as an M/D/1 queue: arrivals at the
+    /// rate of the routed load in `result`, deterministic service of a flit in
+    /// `flit_size / bandwidth`. Per edge latency adds the waiting time of every traversed
+    /// channel to the zero load latency of `model`.
+    pub fn estimate_contention_latency(
+        &self,
+        result: &RoutingResult,
+        model: &LatencyModel,
+    ) -> Result<QueueingReport, ManycoreError> {
+        let channels = self
+            .result_utilisation(result)
+            .channels()
+            .iter()
+            .map(|channel| {
+                let waiting_time = match channel.utilisation() {
+                    Some(rho) if *rho < 1.0 => {
+                        let service_time = model.flit_size() / f64::from(*channel.bandwidth());
+                        Some(rho * service_time / (2.0 * (1.0 - rho)))
+                    }
+                    // Idle channels without bandwidth never queue.
+                    None if *channel.load() == 0 => Some(0.0),
+                    _ => None,
+                };
+
+                ChannelQueue {
+                    core_id: *channel.core_id(),
+                    direction: *channel.direction(),
+                    utilisation: *channel.utilisation(),
+                    waiting_time,
+                }
+            })
+            .collect::<Vec<ChannelQueue>>();
+
+        let waiting_times: BTreeMap<ChannelIDT, Option<f64>> = channels
+            .iter()
+            .map(|c| ((c.core_id, c.direction), c.waiting_time))
+            .collect();
+
+        let mut edges = Vec::with_capacity(result.edge_routes().routes().len());
+        for route in result.edge_routes().routes() {
+            let zero_load = self.edge_latency(route, model)?;
+
+            let waiting_time = route
+                .hops()
+                .iter()
+                .filter(|hop| *hop.routing_type() == RoutingType::OutputChannel)
+                .map(|hop| {
+                    waiting_times
+                        .get(&(*hop.core_id(), *hop.direction()))
+                        .copied()
+                        .flatten()
+                })
+                .sum::<Option<f64>>();
+
+            edges.push(EdgeContentionLatency {
+                edge_index: *route.edge_index(),
+                from: *route.from(),
+                to: *route.to(),
+                zero_load_latency: *zero_load.latency(),
+                waiting_time,
+                latency: waiting_time.map(|w| zero_load.latency() + w),
+            });
+        }
+
+        Ok(QueueingReport { channels, edges })
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, neighbour, routing_error, Directions, ElementIDT, LoadT, ManycoreSystem,
+    RoutingAlgorithms, RoutingResult, WithID,
+};
+
+/// Traffic entering and leaving a router.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct RouterLoad {
+    /// The ID of the router's core.
+    router_id: ElementIDT,
+    /// Load entering through each input port, from a neighbouring router or a source.
+    /// Ports without load are omitted.
+    inputs: BTreeMap<Directions, LoadT>,
+    /// Load injected by the task on the local core.
+    injection: LoadT,
+    /// Load ejected to the task on the local core.
+    ejection: LoadT,
+    /// Total load entering the router, local injection included.
+    throughput: LoadT,
+}
+
+impl RouterLoad {
+    /// Returns the load entering through the input port in the given direction.
+    pub fn input_load(&self, direction: &Directions) -> LoadT {
+        self.inputs.get(direction).copied().unwrap_or_default()
+    }
+}
+
+impl ManycoreSystem {
+    /// Builds the [`RouterLoad`] of every router from the channel and source loads
+    /// `channel_load` and `source_load` assign. Traffic injected and ejected by tasks is
+    /// taken from the task graph, so loads must have been routed from it.
+    fn router_loads_from(
+        &self,
+        algorithm: Option<&RoutingAlgorithms>,
+        channel_load: impl Fn(ElementIDT, Directions) -> LoadT,
+        source_load: impl Fn(ElementIDT, Directions) -> LoadT,
+    ) -> Result<Vec<RouterLoad>, ManycoreError> {
+        if algorithm == Some(&RoutingAlgorithms::Observed) {
+            return Err(routing_error(
+                "Observed loads are not routed from the task graph, their injected and ejected traffic is unknown."
+                    .into(),
+            ));
+        }
+
+        let overflow = |router_id: ElementIDT| {
+            routing_error(format!(
+                "Load through the router of Core with ID {router_id} overflowed."
+            ))
+        };
+
+        // Traffic between the local core and its router, by core.
+        let mut injection: Vec<LoadT> = vec![0; self.cores.list().len()];
+        let mut ejection: Vec<LoadT> = vec![0; self.cores.list().len()];
+        for edge in self.task_graph.edges() {
+            for (task_id, local) in [(edge.from(), &mut injection), (edge.to(), &mut ejection)] {
+                if let Some(load) = self
+                    .task_core_map
+                    .get(task_id)
+                    .and_then(|core_id| local.get_mut(*core_id))
+                {
+                    *load = load
+                        .checked_add(*edge.communication_cost())
+                        .ok_or(routing_error(format!(
+                            "Load from task {} to task {} overflowed.",
+                            edge.from(),
+                            edge.to()
+                        )))?;
+                }
+            }
+        }
+
+        let mut routers = Vec::with_capacity(self.cores.list().len());
+        for core in self.cores.list() {
+            let router_id = *core.id();
+            let mut inputs = BTreeMap::new();
+
+            for direction in [
+                Directions::North,
+                Directions::South,
+                Directions::West,
+                Directions::East,
+            ] {
+                // A neighbour's channel pointing back at this router, or a source on the border.
+                let load = match neighbour(
+                    router_id,
+                    direction,
+                    self.columns_in_id_space,
+                    self.rows_in_id_space,
+                ) {
+                    Some(neighbour_id) => channel_load(neighbour_id, direction.opposite()),
+                    None => source_load(router_id, direction),
+                };
+
+                if load != 0 {
+                    inputs.insert(direction, load);
+                }
+            }
+
+            let core_index = usize::from(router_id);
+            let throughput = inputs
+                .values()
+                .try_fold(injection[core_index], |total: LoadT, load| {
+                    total.checked_add(*load)
+                })
+                .ok_or_else(|| overflow(router_id))?;
+
+            routers.push(RouterLoad {
+                router_id,
+                inputs,
+                injection: injection[core_index],
+                ejection: ejection[core_index],
+                throughput,
+            });
+        }
+
+        Ok(routers)
+    }
+
+    /// Reports the input port loads and throughput of every router, indexed by core ID,
+    /// given the loads the system was last routed with. Observed loads are rejected.
+    pub fn router_loads(&self) -> Result<Vec<RouterLoad>, ManycoreError> {
+        let cores = self.cores.list();
+
+        self.router_loads_from(
+            self.routing_algorithm.as_ref(),
+            |core_id, direction| {
+                cores
+                    .get(usize::from(core_id))
+                    .and_then(|core| core.channels().channel().get(&direction))
+                    .map(|channel| *channel.current_load())
+                    .unwrap_or_default()
+            },
+            |core_id, direction| {
+                cores
+                    .get(usize::from(core_id))
+                    .and_then(|core| core.source_loads().as_ref())
+                    .and_then(|loads| loads.get(&direction))
+                    .copied()
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Reports the input port loads and throughput of every router, indexed by core ID,
+    /// given the loads in `result`, leaving the system untouched. Observed results are
+    /// rejected.
+    pub fn result_router_loads(
+        &self,
+        result: &RoutingResult,
+    ) -> Result<Vec<RouterLoad>, ManycoreError> {
+        self.router_loads_from(
+            Some(result.algorithm()),
+            |core_id, direction| result.channel_load(core_id, direction),
+            |core_id, direction| result.source_load(core_id, direction),
+        )
+    }
+}
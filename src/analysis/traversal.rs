@@ -0,0 +1,239 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, ChannelIDT, EdgeRoute, LatencyModel, ManycoreSystem,
+    RoutingAlgorithms, RoutingType,
+};
+
+/// Delay another edge can impose on an analysed edge by sharing channels with it.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct Interference {
+    /// Index of the interfering edge in the task graph.
+    edge_index: usize,
+    /// Interfering edge source task.
+    from: u16,
+    /// Interfering edge destination task.
+    to: u16,
+    /// Channels both edges traverse, in the analysed edge's traversal order.
+    shared_channels: Vec<ChannelIDT>,
+    /// Upper bound on the time the interfering edge can stall the analysed edge, its own
+    /// indirect and back-pressure interference included.
+    delay: f64,
+}
+
+/// Upper bound on the delivery latency of a task graph edge.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct TraversalTimeBound {
+    /// Index of the edge in the task graph.
+    edge_index: usize,
+    /// Edge source task.
+    from: u16,
+    /// Edge destination task.
+    to: u16,
+    /// Latency without contention, see [`LatencyModel`].
+    zero_load_latency: f64,
+    /// Edges sharing at least one channel, in task graph edge order.
+    interference: Vec<Interference>,
+    /// Edges sharing no channel with the analysed edge that can still delay it, by delaying
+    /// one of its interfering edges, in task graph edge order.
+    indirect_interference: Vec<usize>,
+    /// Zero load latency plus the delay of every interfering edge.
+    bound: f64,
+}
+
+/// Worst case traversal time of every task graph edge.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct TraversalTimeReport {
+    /// The dimension ordered algorithm the edges were routed with.
+    algorithm: RoutingAlgorithms,
+    /// Bounds in task graph edge order.
+    edges: Vec<TraversalTimeBound>,
+}
+
+/// Routed edges the bounds are computed over, with memoised holding times.
+struct TraversalAnalysis<'a> {
+    routes: &'a [EdgeRoute],
+    /// Channels of every edge, in traversal order.
+    channels: Vec<Vec<ChannelIDT>>,
+    /// Zero load latency of every edge.
+    zero_load: Vec<f64>,
+    /// Holding time and interfering edges of `(edge, channel position)` pairs worked out so far.
+    holding: BTreeMap<(usize, usize), (f64, BTreeSet<usize>)>,
+    /// Pairs being worked out, to detect cyclic channel dependencies.
+    pending: BTreeSet<(usize, usize)>,
+}
+
+impl TraversalAnalysis<'_> {
+    /// Edges other than `edge_index` traversing any of `channels`, each with the position in
+    /// its own route of the first channel it shares, and the shared channels in the order
+    /// they appear in `channels`.
+    fn sharing(
+        &self,
+        edge_index: usize,
+        channels: &[ChannelIDT],
+    ) -> BTreeMap<usize, (usize, Vec<ChannelIDT>)> {
+        let mut sharing = BTreeMap::new();
+
+        for (other, other_channels) in self.channels.iter().enumerate() {
+            if other == edge_index {
+                continue;
+            }
+
+            if let Some(first) = other_channels.iter().position(|c| channels.contains(c)) {
+                let shared = channels
+                    .iter()
+                    .filter(|c| other_channels.contains(c))
+                    .copied()
+                    .collect();
+                sharing.insert(other, (first, shared));
+            }
+        }
+
+        sharing
+    }
+
+    /// Upper bound on the time from `edge_index` acquiring the channel at `position` in its
+    /// route until its tail is delivered, along with every edge that can stall it meanwhile.
+    fn holding_time(
+        &mut self,
+        edge_index: usize,
+        position: usize,
+    ) -> Result<(f64, BTreeSet<usize>), ManycoreError> {
+        if let Some(holding) = self.holding.get(&(edge_index, position)) {
+            return Ok(holding.clone());
+        }
+        if !self.pending.insert((edge_index, position)) {
+            return Err(routing_error(
+                "Worst case traversal time analysis requires routes without cyclic channel dependencies."
+                    .into(),
+            ));
+        }
+
+        let downstream = self.channels[edge_index][position + 1..].to_vec();
+        let mut time = self.zero_load[edge_index];
+        let mut interferers = BTreeSet::new();
+        for (other, (first, _)) in self.sharing(edge_index, &downstream) {
+            let (delay, indirect) = self.holding_time(other, first)?;
+            time += delay;
+            interferers.insert(other);
+            interferers.extend(indirect);
+        }
+
+        self.pending.remove(&(edge_index, position));
+        self.holding
+            .insert((edge_index, position), (time, interferers.clone()));
+
+        Ok((time, interferers))
+    }
+}
+
+impl ManycoreSystem {
+    /// Bounds the delivery latency of every task graph edge under dimension ordered routing
+    /// ([`RoutingAlgorithms::RowFirst`] or [`RoutingAlgorithms::ColumnFirst`]) with round
+    /// robin arbitration and wormhole switching.
+    ///
+    /// Each edge is assumed to send its communication cost once, as a single packet that
+    /// holds every channel from acquiring it until its tail leaves, and to be delivered
+    /// without ever being refused by its destination. Traversal times are measured from the
+    /// packet entering its first channel. Round robin arbitration never leaves a requested
+    /// channel idle, so an edge only stalls while another edge holds a channel it needs.
+    ///
+    /// Another edge only holds the shared channels after acquiring the first of them, and
+    /// until its own tail is delivered. That holding time is bounded by its zero load
+    /// latency plus the holding time of every edge sharing a channel further along its
+    /// route, recursively. Indirect interference and back-pressure, where an interfering
+    /// edge is itself blocked downstream by edges the analysed edge never meets, are thus
+    /// included. Dimension ordered routes have no cyclic channel dependencies, so the
+    /// recursion always ends.
+    pub fn worst_case_traversal_times(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        model: &LatencyModel,
+    ) -> Result<TraversalTimeReport, ManycoreError> {
+        if !matches!(
+            algorithm,
+            RoutingAlgorithms::RowFirst | RoutingAlgorithms::ColumnFirst
+        ) {
+            return Err(routing_error(format!(
+                "Worst case traversal time analysis requires dimension ordered routing, {algorithm:?} is not supported."
+            )));
+        }
+
+        let result = self.compute_routing(algorithm)?;
+        let routes = result.edge_routes().routes();
+
+        let mut analysis = TraversalAnalysis {
+            routes,
+            channels: routes
+                .iter()
+                .map(|route| {
+                    route
+                        .hops()
+                        .iter()
+                        .filter(|hop| *hop.routing_type() == RoutingType::OutputChannel)
+                        .map(|hop| (*hop.core_id(), *hop.direction()))
+                        .collect()
+                })
+                .collect(),
+            zero_load: routes
+                .iter()
+                .map(|route| Ok(*self.edge_latency(route, model)?.latency()))
+                .collect::<Result<Vec<f64>, ManycoreError>>()?,
+            holding: BTreeMap::new(),
+            pending: BTreeSet::new(),
+        };
+
+        let mut edges = Vec::with_capacity(routes.len());
+        for (edge_index, route) in routes.iter().enumerate() {
+            let sharing = analysis.sharing(edge_index, &analysis.channels[edge_index].clone());
+
+            let mut interference = Vec::with_capacity(sharing.len());
+            let mut indirect_interference = BTreeSet::new();
+            for (other, (first, shared_channels)) in sharing {
+                let (delay, indirect) = analysis.holding_time(other, first)?;
+                indirect_interference.extend(indirect);
+
+                let other_route = &analysis.routes[other];
+                interference.push(Interference {
+                    edge_index: *other_route.edge_index(),
+                    from: *other_route.from(),
+                    to: *other_route.to(),
+                    shared_channels,
+                    delay,
+                });
+            }
+            indirect_interference.remove(&edge_index);
+            for direct in &interference {
+                indirect_interference.remove(&direct.edge_index);
+            }
+
+            let zero_load_latency = analysis.zero_load[edge_index];
+            edges.push(TraversalTimeBound {
+                edge_index: *route.edge_index(),
+                from: *route.from(),
+                to: *route.to(),
+                zero_load_latency,
+                bound: zero_load_latency + interference.iter().map(|i| i.delay).sum::<f64>(),
+                interference,
+                indirect_interference: indirect_interference
+                    .into_iter()
+                    .map(|i| *analysis.routes[i].edge_index())
+                    .collect(),
+            });
+        }
+
+        Ok(TraversalTimeReport {
+            algorithm: algorithm.clone(),
+            edges,
+        })
+    }
+}
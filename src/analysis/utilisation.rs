@@ -0,0 +1,102 @@
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{utilisation, Directions, ElementIDT, LoadT, ManycoreSystem, RoutingResult, WithID};
+
+/// Load and utilisation of a single channel.
+#[derive(Serialize, Debug, PartialEq, Clone, Copy, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct ChannelUtilisation {
+    /// The ID of the core the channel leaves.
+    core_id: ElementIDT,
+    /// The channel direction.
+    direction: Directions,
+    /// The routed load.
+    load: LoadT,
+    /// The channel bandwidth.
+    bandwidth: u16,
+    /// Ratio of load to bandwidth. [`None`] if the channel has no bandwidth.
+    utilisation: Option<f64>,
+}
+
+impl ChannelUtilisation {
+    /// Whether the load exceeds the channel bandwidth.
+    pub fn is_over_subscribed(&self) -> bool {
+        self.load > LoadT::from(self.bandwidth)
+    }
+}
+
+/// Utilisation of every channel in the system.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct UtilisationReport {
+    /// Every channel, ordered by core ID and direction.
+    channels: Vec<ChannelUtilisation>,
+    /// Highest utilisation over channels with bandwidth.
+    max_utilisation: f64,
+    /// Average utilisation over channels with bandwidth.
+    average_utilisation: f64,
+}
+
+impl UtilisationReport {
+    /// Returns the channels whose load exceeds their bandwidth.
+    pub fn over_subscribed(&self) -> Vec<&ChannelUtilisation> {
+        self.channels
+            .iter()
+            .filter(|c| c.is_over_subscribed())
+            .collect()
+    }
+}
+
+impl ManycoreSystem {
+    /// Builds a [`UtilisationReport`] from the load `load_of` assigns each channel.
+    fn utilisation_report(
+        &self,
+        load_of: impl Fn(ElementIDT, Directions, LoadT) -> LoadT,
+    ) -> UtilisationReport {
+        let mut channels = Vec::new();
+
+        for core in self.cores.list() {
+            for (direction, channel) in core.channels().channel() {
+                let load = load_of(*core.id(), *direction, *channel.current_load());
+
+                channels.push(ChannelUtilisation {
+                    core_id: *core.id(),
+                    direction: *direction,
+                    load,
+                    bandwidth: *channel.bandwidth(),
+                    utilisation: utilisation(load, *channel.bandwidth()),
+                });
+            }
+        }
+
+        let utilisations = channels
+            .iter()
+            .filter_map(|c| c.utilisation)
+            .collect::<Vec<f64>>();
+
+        UtilisationReport {
+            max_utilisation: utilisations.iter().copied().fold(0.0, f64::max),
+            average_utilisation: if utilisations.is_empty() {
+                0.0
+            } else {
+                utilisations.iter().sum::<f64>() / utilisations.len() as f64
+            },
+            channels,
+        }
+    }
+
+    /// Reports the utilisation of every channel given the loads the system was last
+    /// routed with.
+    pub fn utilisation(&self) -> UtilisationReport {
+        self.utilisation_report(|_, _, current_load| current_load)
+    }
+
+    /// Reports the utilisation of every channel given the loads in `result`, leaving the
+    /// system untouched.
+    pub fn result_utilisation(&self, result: &RoutingResult) -> UtilisationReport {
+        self.utilisation_report(|core_id, direction, _| result.channel_load(core_id, direction))
+    }
+}
@@ -2,7 +2,7 @@ use getset::{Getters, MutGetters};
 use manycore_utils::BTreeVector;
 use serde::{Deserialize, Serialize};
 
-use crate::BorderRouter;
+use crate::{BorderRouter, LoadT};
 
 use super::SinkSourceDirection;
 
@@ -18,7 +18,7 @@ pub struct Source {
     task_id: u16,
     #[serde(rename = "@actualComCost", skip_serializing_if = "Option::is_none")]
     #[getset(get = "pub")]
-    actual_com_cost: Option<u16>,
+    actual_com_cost: Option<LoadT>,
 }
 
 impl BTreeVector<u16> for Source {
@@ -46,7 +46,7 @@ impl Source {
         core_id: usize,
         direction: SinkSourceDirection,
         task_id: u16,
-        actual_com_cost: Option<u16>,
+        actual_com_cost: Option<LoadT>,
     ) -> Self {
         Self {
             core_id,
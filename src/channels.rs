@@ -1,12 +1,12 @@
 use std::{collections::BTreeMap, fmt::Display};
 
-use getset::{Getters, MutGetters};
+use getset::{Getters, MutGetters, Setters};
 use manycore_utils::{deserialize_btree_vector, serialise_btreemap, BTreeVector};
 use serde::{Deserialize, Serialize};
 
 use crate::error::ManycoreError;
 use crate::utils::attrs::deserialize_attrs;
-use crate::{ManycoreErrorKind, WithXMLAttributes};
+use crate::{ElementIDT, LoadT, ManycoreErrorKind, WithXMLAttributes};
 
 static NORTH: &str = "North";
 static SOUTH: &str = "South";
@@ -22,6 +22,18 @@ pub enum Directions {
     East,
 }
 
+impl Directions {
+    /// Returns the direction pointing the opposite way.
+    pub fn opposite(&self) -> Self {
+        match self {
+            Directions::North => Directions::South,
+            Directions::South => Directions::North,
+            Directions::West => Directions::East,
+            Directions::East => Directions::West,
+        }
+    }
+}
+
 impl Display for Directions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", String::from(self))
@@ -55,8 +67,11 @@ impl TryFrom<&str> for Directions {
     }
 }
 
+/// Type identifying a [`Channel`] by the ID of the core it leaves and its direction.
+pub type ChannelIDT = (ElementIDT, Directions);
+
 /// Object representation of a `<Channel>` element as provided in XML input.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters, Setters)]
 pub struct Channel {
     /// The channel's direction.
     #[serde(rename = "@direction")]
@@ -67,11 +82,15 @@ pub struct Channel {
     bandwidth: u16,
     #[serde(rename = "@actualComCost")]
     #[getset(get = "pub")]
-    actual_com_cost: u16,
+    actual_com_cost: LoadT,
     /// The load on the channel
     #[serde(skip)]
     #[getset(get = "pub")]
-    current_load: u16,
+    current_load: LoadT,
+    /// Whether the channel is marked as faulty.
+    #[serde(skip)]
+    #[getset(get = "pub", set = "pub")]
+    faulty: bool,
     /// Any other channel attribute present in the XML.
     #[serde(
         flatten,
@@ -87,7 +106,7 @@ impl Channel {
     /// Instantiates a new [`Channel`] instance.
     pub(crate) fn new(
         direction: Directions,
-        actual_com_cost: u16,
+        actual_com_cost: LoadT,
         bandwidth: u16,
         other_attributes: Option<BTreeMap<String, String>>,
     ) -> Self {
@@ -97,15 +116,49 @@ impl Channel {
             bandwidth,
             other_attributes,
             current_load: 0,
+            faulty: false,
         }
     }
 
-    /// Adds to the current load of a [`Channel`].
-    pub(crate) fn add_to_load(&mut self, cost: u16) {
-        self.current_load += cost;
+    /// Error for a load change that does not fit in a [`LoadT`].
+    fn load_error(&self, outcome: &str) -> ManycoreError {
+        ManycoreError::new(ManycoreErrorKind::RoutingError(format!(
+            "Load on {} channel {outcome}.",
+            self.direction
+        )))
+    }
+
+    /// Adds to the current load of a [`Channel`]. Errors if the load would overflow.
+    pub(crate) fn add_to_load(&mut self, cost: LoadT) -> Result<(), ManycoreError> {
+        self.current_load = self
+            .current_load
+            .checked_add(cost)
+            .ok_or_else(|| self.load_error("overflowed"))?;
+
+        Ok(())
+    }
+
+    /// Removes from the current load of a [`Channel`]. Errors if the load would underflow.
+    pub(crate) fn remove_from_load(&mut self, cost: LoadT) -> Result<(), ManycoreError> {
+        self.current_load = self
+            .current_load
+            .checked_sub(cost)
+            .ok_or_else(|| self.load_error("underflowed"))?;
+
+        Ok(())
+    }
+
+    /// Ratio of the current load to the bandwidth. Returns [`None`] if the channel has no bandwidth.
+    pub fn utilisation(&self) -> Option<f64> {
+        utilisation(self.current_load, self.bandwidth)
     }
 }
 
+/// Ratio of `load` to `bandwidth`. Returns [`None`] if `bandwidth` is zero.
+pub(crate) fn utilisation(load: LoadT, bandwidth: u16) -> Option<f64> {
+    (bandwidth != 0).then(|| load as f64 / f64::from(bandwidth))
+}
+
 impl BTreeVector<Directions> for Channel {
     fn key(&self) -> Directions {
         self.direction
@@ -149,22 +202,33 @@ impl Channels {
             .for_each(|(_, c)| c.current_load = 0);
     }
 
+    /// Returns the [`Channel`] in the given [`Directions`], or an error if it is missing.
+    fn directed_channel_mut(
+        &mut self,
+        direction: Directions,
+    ) -> Result<&mut Channel, ManycoreError> {
+        self.channel
+            .get_mut(&direction)
+            .ok_or(ManycoreError::new(ManycoreErrorKind::RoutingError(
+                format!("Missing {} channels.", direction),
+            )))
+    }
+
     /// Adds to the [`Channel`]'s load in the given [`Directions`] within the provided [`Channels`] instance.
     pub(crate) fn add_to_load(
         &mut self,
-        cost: u16,
+        cost: LoadT,
         direction: Directions,
     ) -> Result<(), ManycoreError> {
-        self.channel
-            .get_mut(&direction)
-            .ok_or(ManycoreError::new(
-                crate::error::ManycoreErrorKind::RoutingError(format!(
-                    "Missing {} channels.",
-                    direction
-                )),
-            ))?
-            .add_to_load(u16::from(cost));
+        self.directed_channel_mut(direction)?.add_to_load(cost)
+    }
 
-        Ok(())
+    /// Removes from the [`Channel`]'s load in the given [`Directions`] within the provided [`Channels`] instance.
+    pub(crate) fn remove_from_load(
+        &mut self,
+        cost: LoadT,
+        direction: Directions,
+    ) -> Result<(), ManycoreError> {
+        self.directed_channel_mut(direction)?.remove_from_load(cost)
     }
 }
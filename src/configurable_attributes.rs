@@ -6,7 +6,7 @@ use serde::Serialize;
 use crate::{RoutingAlgorithms, ROUTING_KEY};
 
 #[cfg(doc)]
-use crate::{Channel, Core, Router};
+use crate::{Channel, Core, ManycoreSystem, Router};
 
 /// This trait is to be implemented by every object that is a representation of an XML element
 /// which constains arrbitrary attributes.
@@ -38,6 +38,14 @@ pub enum AttributeType {
     Coordinates,
     Boolean,
     Routing,
+    /// Ratio of a channel's load to its bandwidth, see [`Channel::utilisation`].
+    Utilisation,
+    /// Energy spent by an element, see [`ManycoreSystem::energy`].
+    Energy,
+    /// Load entering a router through each input port, see [`ManycoreSystem::router_loads`].
+    InputLoads,
+    /// Total load entering a router, see [`ManycoreSystem::router_loads`].
+    Throughput,
 }
 
 /// Helper struct to provide a user friendly string of an attribute (`display` field )and its type (`_type` field).
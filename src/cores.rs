@@ -1,6 +1,6 @@
 use crate::{
-    channels::Channels, router::*, routing_error, utils, Directions, ElementIDT, ManycoreError,
-    SinkSourceDirection, WithID, WithXMLAttributes,
+    channels::Channels, router::*, routing_error, utils, Directions, ElementIDT, LoadT,
+    ManycoreError, SinkSourceDirection, WithID, WithXMLAttributes,
 };
 use getset::{Getters, MutGetters, Setters};
 use serde::{Deserialize, Serialize};
@@ -102,7 +102,7 @@ pub struct Core {
     channels: Channels,
     /// Map with core's incoming source loads.
     #[serde(skip)]
-    source_loads: Option<BTreeMap<Directions, u16>>,
+    source_loads: Option<BTreeMap<Directions, LoadT>>,
     #[serde(skip)]
     matrix_edge: Option<EdgePosition>,
     /// Any other core attribute present in the XML.
@@ -182,7 +182,7 @@ impl Core {
     /// Utility function to add to a source load.
     pub(crate) fn add_source_load(
         &mut self,
-        load: u16,
+        load: LoadT,
         direction: &Directions,
     ) -> Result<(), ManycoreError> {
         if let None = self.matrix_edge {
@@ -191,11 +191,39 @@ impl Core {
                     format!("Malformed TaskGraph: Attempted to add load from a Source on Core with ID {}. The Core is not on the matrix edge.", self.id)));
         }
 
-        self.source_loads
+        let current_load = self
+            .source_loads
             .get_or_insert(BTreeMap::new())
             .entry(*direction)
-            .and_modify(|current_load| *current_load = current_load.saturating_add(load))
-            .or_insert(load);
+            .or_insert(0);
+
+        *current_load = current_load.checked_add(load).ok_or(routing_error(format!(
+            "Load on the {} source channel of Core with ID {} overflowed.",
+            direction, self.id
+        )))?;
+
+        Ok(())
+    }
+
+    /// Utility function to remove from a source load.
+    pub(crate) fn remove_source_load(
+        &mut self,
+        load: LoadT,
+        direction: &Directions,
+    ) -> Result<(), ManycoreError> {
+        let current_load = self
+            .source_loads
+            .as_mut()
+            .and_then(|loads| loads.get_mut(direction))
+            .ok_or(routing_error(format!(
+                "Core with ID {} has no load on its {} source channel.",
+                self.id, direction
+            )))?;
+
+        *current_load = current_load.checked_sub(load).ok_or(routing_error(format!(
+            "Load on the {} source channel of Core with ID {} underflowed.",
+            direction, self.id
+        )))?;
 
         Ok(())
     }
@@ -0,0 +1,236 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use getset::Getters;
+
+use crate::{
+    error::ManycoreError, ChannelIDT, EdgeRoutes, ElementIDT, ManycoreSystem, RoutingAlgorithms,
+    RoutingType,
+};
+
+#[cfg(doc)]
+use crate::Channel;
+
+/// The channel dependency graph of a routing configuration.
+/// A [`Channel`] depends on another if some route holds the former while requesting the latter.
+/// The routing configuration is deadlock-free if the graph has no cycles.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct ChannelDependencyGraph {
+    /// Map of each channel to the channels it depends on.
+    dependencies: BTreeMap<ChannelIDT, BTreeSet<ChannelIDT>>,
+}
+
+impl ChannelDependencyGraph {
+    /// Builds the dependency graph of the provided routes. Each route is the ordered sequence
+    /// of channels it traverses.
+    pub fn from_routes<'a, I>(routes: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Vec<ChannelIDT>>,
+    {
+        let mut dependencies: BTreeMap<ChannelIDT, BTreeSet<ChannelIDT>> = BTreeMap::new();
+
+        for route in routes {
+            for channel in route {
+                dependencies.entry(*channel).or_default();
+            }
+
+            for pair in route.windows(2) {
+                dependencies.entry(pair[0]).or_default().insert(pair[1]);
+            }
+        }
+
+        Self { dependencies }
+    }
+
+    /// Returns the strongly connected components of the graph, using an iterative
+    /// implementation of Tarjan's algorithm.
+    fn strongly_connected_components(&self) -> Vec<Vec<ChannelIDT>> {
+        let nodes: Vec<&ChannelIDT> = self.dependencies.keys().collect();
+        let index_of: HashMap<&ChannelIDT, usize> =
+            nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+        let successors: Vec<Vec<usize>> = self
+            .dependencies
+            .values()
+            .map(|next| next.iter().map(|n| index_of[n]).collect())
+            .collect();
+
+        let mut index = vec![usize::MAX; nodes.len()];
+        let mut low_link = vec![0; nodes.len()];
+        let mut on_stack = vec![false; nodes.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..nodes.len() {
+            if index[root] != usize::MAX {
+                continue;
+            }
+
+            // (node, next successor to inspect)
+            let mut call_stack = vec![(root, 0usize)];
+            index[root] = next_index;
+            low_link[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((node, child)) = call_stack.pop() {
+                if let Some(&successor) = successors[node].get(child) {
+                    call_stack.push((node, child + 1));
+
+                    if index[successor] == usize::MAX {
+                        index[successor] = next_index;
+                        low_link[successor] = next_index;
+                        next_index += 1;
+                        stack.push(successor);
+                        on_stack[successor] = true;
+                        call_stack.push((successor, 0));
+                    } else if on_stack[successor] {
+                        low_link[node] = low_link[node].min(index[successor]);
+                    }
+
+                    continue;
+                }
+
+                // All successors inspected, propagate low link to the caller.
+                if let Some((parent, _)) = call_stack.last() {
+                    low_link[*parent] = low_link[*parent].min(low_link[node]);
+                }
+
+                if low_link[node] == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(*nodes[member]);
+
+                        if member == node {
+                            break;
+                        }
+                    }
+
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Finds the shortest cycle through `start` that stays within `component`.
+    fn cycle_through(
+        &self,
+        start: ChannelIDT,
+        component: &BTreeSet<ChannelIDT>,
+    ) -> Option<Vec<ChannelIDT>> {
+        let mut parents: HashMap<ChannelIDT, ChannelIDT> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(current) = queue.pop_front() {
+            for next in self.dependencies.get(&current)? {
+                if !component.contains(next) {
+                    continue;
+                }
+
+                if *next == start {
+                    // Walk back to the start to recover the cycle.
+                    let mut cycle = vec![current];
+                    let mut cursor = current;
+                    while cursor != start {
+                        cursor = parents[&cursor];
+                        cycle.push(cursor);
+                    }
+                    cycle.reverse();
+
+                    return Some(cycle);
+                }
+
+                if !parents.contains_key(next) {
+                    parents.insert(*next, current);
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns one cycle for every strongly connected component of the graph that contains
+    /// any. Each cycle is listed as the sequence of `(core id, Directions)` channels it
+    /// traverses, starting from its smallest channel. An empty result means the routing
+    /// configuration is deadlock-free.
+    pub fn cycles(&self) -> Vec<Vec<ChannelIDT>> {
+        let mut cycles: Vec<Vec<ChannelIDT>> = self
+            .strongly_connected_components()
+            .into_iter()
+            .filter_map(|component| {
+                let members: BTreeSet<ChannelIDT> = component.into_iter().collect();
+                let start = *members.first()?;
+
+                self.cycle_through(start, &members)
+            })
+            .collect();
+
+        cycles.sort();
+
+        cycles
+    }
+
+    /// Determines if the routing configuration is free from cyclic channel dependencies.
+    pub fn is_deadlock_free(&self) -> bool {
+        self.cycles().is_empty()
+    }
+}
+
+impl From<&EdgeRoutes> for ChannelDependencyGraph {
+    fn from(edge_routes: &EdgeRoutes) -> Self {
+        // Source ports are not channels, only output hops can hold or request one.
+        let routes: Vec<Vec<ChannelIDT>> = edge_routes
+            .routes()
+            .iter()
+            .map(|route| {
+                route
+                    .hops()
+                    .iter()
+                    .filter(|hop| *hop.routing_type() == RoutingType::OutputChannel)
+                    .map(|hop| (*hop.core_id(), *hop.direction()))
+                    .collect()
+            })
+            .collect();
+
+        ChannelDependencyGraph::from_routes(&routes)
+    }
+}
+
+impl ManycoreSystem {
+    /// Builds the channel dependency graph of the task graph edges routed with the given algorithm.
+    pub fn task_graph_channel_dependencies(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<ChannelDependencyGraph, ManycoreError> {
+        let routes = self.task_graph_channel_paths(algorithm)?;
+
+        Ok(ChannelDependencyGraph::from_routes(&routes))
+    }
+
+    /// Builds the channel dependency graph of the given algorithm applied between every pair
+    /// of cores in the mesh. Pairs the algorithm cannot connect do not contribute any dependency.
+    pub fn mesh_channel_dependencies(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<ChannelDependencyGraph, ManycoreError> {
+        let number_of_cores = ElementIDT::try_from(self.cores().list().len())?;
+
+        let mut routes = Vec::new();
+        for start_id in 0..number_of_cores {
+            for destination_id in (0..number_of_cores).filter(|d| *d != start_id) {
+                if let Some(route) =
+                    self.core_to_core_channel_path(algorithm, start_id, destination_id)?
+                {
+                    routes.push(route);
+                }
+            }
+        }
+
+        Ok(ChannelDependencyGraph::from_routes(&routes))
+    }
+}
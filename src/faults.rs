@@ -0,0 +1,81 @@
+use crate::{
+    error::ManycoreError, get_core, routing_error, Directions, ManycoreSystem, WithXMLAttributes,
+};
+
+#[cfg(doc)]
+use crate::{Channel, Router};
+
+/// Utility function to determine if an element carries the `key="value"` attribute.
+fn attribute_matches<T: WithXMLAttributes>(element: &T, key: &str, value: &str) -> bool {
+    element
+        .other_attributes()
+        .as_ref()
+        .and_then(|attributes| attributes.get(key))
+        .is_some_and(|v| v == value)
+}
+
+impl ManycoreSystem {
+    /// Marks every [`Router`] and [`Channel`] whose `key` attribute equals `value` as faulty.
+    /// Keys are the XML attribute names, e.g. `"status"`. The `@` prefix attributes carry in
+    /// the attributes map is added if missing. Returns the number of elements that were marked.
+    pub fn mark_faults_by_attribute(&mut self, key: &str, value: &str) -> usize {
+        let key = format!("@{}", key.strip_prefix('@').unwrap_or(key));
+        let mut marked = 0;
+
+        for core in self.cores_mut().list_mut() {
+            let router = core.router_mut();
+            if attribute_matches(router, &key, value) {
+                router.set_faulty(true);
+                marked += 1;
+            }
+
+            for channel in core.channels_mut().channel_mut().values_mut() {
+                if attribute_matches(channel, &key, value) {
+                    channel.set_faulty(true);
+                    marked += 1;
+                }
+            }
+        }
+
+        marked
+    }
+
+    /// Marks the [`Router`] connected to the requested core as faulty (or healthy).
+    pub fn set_router_fault(&mut self, core_id: usize, faulty: bool) -> Result<(), ManycoreError> {
+        get_core(self.cores_mut(), core_id)?
+            .router_mut()
+            .set_faulty(faulty);
+
+        Ok(())
+    }
+
+    /// Marks the requested core's [`Channel`] in the given direction as faulty (or healthy).
+    pub fn set_channel_fault(
+        &mut self,
+        core_id: usize,
+        direction: Directions,
+        faulty: bool,
+    ) -> Result<(), ManycoreError> {
+        get_core(self.cores_mut(), core_id)?
+            .channels_mut()
+            .channel_mut()
+            .get_mut(&direction)
+            .ok_or(routing_error(format!(
+                "Core {core_id} has no {direction} channel."
+            )))?
+            .set_faulty(faulty);
+
+        Ok(())
+    }
+
+    /// Marks every [`Router`] and [`Channel`] in the system as healthy.
+    pub fn clear_faults(&mut self) {
+        for core in self.cores_mut().list_mut() {
+            core.router_mut().set_faulty(false);
+
+            for channel in core.channels_mut().channel_mut().values_mut() {
+                channel.set_faulty(false);
+            }
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use std::collections::BTreeMap;
 use manycore_utils::{deserialize_btree_vector, serialise_btreemap, BTreeVector};
-use getset::{Getters, MutGetters};
+use getset::{Getters, MutGetters, Setters};
 use serde::{Deserialize, Serialize};
 
+use crate::LoadT;
+
 /// Object representation of an `<Edge>` element in input XML.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Getters, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Getters, Setters, Clone)]
 #[serde(rename_all = "PascalCase")]
 #[getset(get = "pub")]
 pub struct Edge {
@@ -16,13 +18,14 @@ pub struct Edge {
     to: u16,
     /// Edge cost.
     #[serde(rename = "@communicationCost")]
-    communication_cost: u16,
+    #[getset(set = "pub(crate)")]
+    communication_cost: LoadT,
 }
 
 impl Edge {
     #[cfg(test)]
     /// Instantiates a new edge.
-    pub(crate) fn new(from: u16, to: u16, communication_cost: u16) -> Self {
+    pub(crate) fn new(from: u16, to: u16, communication_cost: LoadT) -> Self {
         Self {
             from,
             to,
@@ -1,10 +1,13 @@
 //! A parser for Manycore System XML configuration files
 
+mod analysis;
 mod borders;
 mod channels;
 mod configurable_attributes;
 mod cores;
+mod deadlock;
 mod error;
+mod faults;
 mod graph;
 mod info;
 mod router;
@@ -15,9 +18,11 @@ mod utils;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 
+pub use crate::analysis::*;
 pub use crate::borders::*;
 pub use crate::channels::*;
 pub use crate::cores::*;
+pub use crate::deadlock::*;
 pub use crate::error::*;
 pub use crate::graph::*;
 pub use crate::router::*;
@@ -32,11 +37,17 @@ pub static COORDINATES_KEY: &'static str = "@coordinates";
 pub static BORDER_ROUTERS_KEY: &'static str = "@borderRouters";
 pub static ROUTING_KEY: &'static str = "@routingAlgorithm";
 pub static TASK_COST_KEY: &'static str = "@taskCost";
+pub static UTILISATION_KEY: &'static str = "@utilisation";
+pub static ENERGY_KEY: &'static str = "@energy";
+pub static INPUT_LOADS_KEY: &'static str = "@inputLoads";
+pub static THROUGHPUT_KEY: &'static str = "@throughput";
 
 /// Type for rows and columns
 pub type SystemDimensionsT = u8;
 /// Type for Element IDs
 pub type ElementIDT = u16;
+/// Type for communication costs and the loads they accumulate into.
+pub type LoadT = u64;
 /// Type that can fully contain [`SystemDimensionsT`] + negative space.
 /// Must also contain [`ElementIDT`].
 type WrappingSystemDimensionsT = i32;
@@ -97,6 +108,22 @@ pub struct ManycoreSystem {
     task_core_map: HashMap<u16, usize>,
     #[serde(skip)]
     #[getset(get = "pub")]
+    /// This is not part of the XML and is populated by routing. It stores the hop sequence followed by each task graph edge.
+    edge_routes: EdgeRoutes,
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    /// This is not part of the XML and is populated by routing. It stores the algorithm the current loads were routed with, which edits re-route them with.
+    routing_algorithm: Option<RoutingAlgorithms>,
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    /// This is not part of the XML and is populated by routing. It stores whether the current loads are multicast tree loads, which edits recompute in full.
+    routed_multicast: bool,
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    /// This is not part of the XML and is populated by [`ManycoreSystem::apply_energy`]. It stores the energy spent by each element under the current loads.
+    energy: Option<EnergyReport>,
+    #[serde(skip)]
+    #[getset(get = "pub")]
     /// This is not part of the XML and is used to provided the frontend with a list of attributes that can be requested for rendering.
     configurable_attributes: ConfigurableAttributes,
 }
@@ -154,8 +181,15 @@ impl ManycoreSystem {
         core_attributes.insert_manual(ID_KEY, AttributeType::Text);
         core_attributes.insert_manual(COORDINATES_KEY, AttributeType::Coordinates);
         core_attributes.insert_manual(TASK_COST_KEY, AttributeType::Boolean);
+        core_attributes.insert_manual(ENERGY_KEY, AttributeType::Energy);
+        // Manually insert router attributes that are not part of the "other_attributes" map.
+        router_attributes.insert_manual(ENERGY_KEY, AttributeType::Energy);
+        router_attributes.insert_manual(INPUT_LOADS_KEY, AttributeType::InputLoads);
+        router_attributes.insert_manual(THROUGHPUT_KEY, AttributeType::Throughput);
         // Manually insert channel attributes that are not part of the "other_attributes" map.
         channel_attributes.insert_manual(ROUTING_KEY, AttributeType::Routing);
+        channel_attributes.insert_manual(UTILISATION_KEY, AttributeType::Utilisation);
+        channel_attributes.insert_manual(ENERGY_KEY, AttributeType::Energy);
 
         // Core id validation tracker
         let mut prev_id: WrappingSystemDimensionsT = -1;
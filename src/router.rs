@@ -1,20 +1,32 @@
 use std::collections::BTreeMap;
 
-use getset::Setters;
+use getset::{Getters, Setters};
 use serde::{Deserialize, Serialize};
 
 use crate::{utils, ElementIDT, WithID, WithXMLAttributes};
 
+pub use self::routing_table::*;
+
+mod routing_table;
+
 #[cfg(doc)]
 use crate::Core;
 
 /// Object representation of a [`Core`]'s router.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Setters)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters, Setters)]
 pub struct Router {
     /// The associated core id (not part of XML).
     #[serde(skip)]
     #[getset(set = "pub")]
     id: ElementIDT,
+    /// Whether the router is marked as faulty (not part of XML).
+    #[serde(skip)]
+    #[getset(get = "pub", set = "pub")]
+    faulty: bool,
+    /// The router's routing table, if any.
+    #[serde(rename = "RoutingTable", skip_serializing_if = "Option::is_none")]
+    #[getset(get = "pub", set = "pub")]
+    routing_table: Option<RoutingTable>,
     /// Any other router attribute present in the XML.
     #[serde(
         flatten,
@@ -30,6 +42,8 @@ impl Router {
     pub fn new(id: ElementIDT, other_attributes: Option<BTreeMap<String, String>>) -> Self {
         Self {
             id,
+            faulty: false,
+            routing_table: None,
             other_attributes,
         }
     }
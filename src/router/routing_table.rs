@@ -0,0 +1,63 @@
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{Directions, ElementIDT};
+
+#[cfg(doc)]
+use crate::Router;
+
+/// Object representation of a `<Entry>` element within a `<RoutingTable>`.
+/// It covers destinations from `from` up to `to`, inclusive. A single destination omits `to`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct RoutingTableEntry {
+    /// First destination core ID covered by the entry.
+    #[serde(rename = "@from")]
+    from: ElementIDT,
+    /// Last destination core ID covered by the entry, if the entry covers a range.
+    #[serde(rename = "@to", skip_serializing_if = "Option::is_none")]
+    to: Option<ElementIDT>,
+    /// The output channel packets for the covered destinations are forwarded to.
+    #[serde(rename = "@direction")]
+    direction: Directions,
+}
+
+impl RoutingTableEntry {
+    /// Instantiates a new [`RoutingTableEntry`] instance.
+    pub fn new(from: ElementIDT, to: Option<ElementIDT>, direction: Directions) -> Self {
+        Self {
+            from,
+            to,
+            direction,
+        }
+    }
+
+    /// Determines if the entry covers the given destination.
+    pub fn covers(&self, destination: ElementIDT) -> bool {
+        (self.from..=self.to.unwrap_or(self.from)).contains(&destination)
+    }
+}
+
+/// Object representation of a [`Router`]'s `<RoutingTable>` element.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct RoutingTable {
+    /// The table entries. The first entry covering a destination wins.
+    #[serde(rename = "Entry", default)]
+    entries: Vec<RoutingTableEntry>,
+}
+
+impl RoutingTable {
+    /// Instantiates a new [`RoutingTable`] instance.
+    pub fn new(entries: Vec<RoutingTableEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the output direction for the given destination, if any entry covers it.
+    pub fn next_hop(&self, destination: ElementIDT) -> Option<Directions> {
+        self.entries
+            .iter()
+            .find(|entry| entry.covers(destination))
+            .map(|entry| entry.direction)
+    }
+}
@@ -1,9 +1,29 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 
 use serde::{Deserialize, Serialize};
 
+pub use self::edge_routes::*;
+pub use self::events::*;
+pub use self::export::RoutingTables;
+pub use self::multicast::MulticastRouting;
+pub use self::multipath::*;
+pub use self::result::RoutingResult;
+pub use self::table::RoutingTableIssue;
+
+mod edge_routes;
+mod events;
+mod export;
+mod incremental;
+mod multicast;
+mod multipath;
+mod result;
+mod table;
+
+use crate::utils::rng::SplitMix64;
 use crate::{
-    error::ManycoreError, BorderRouter, Borders, Core, Cores, Directions, Edge, ElementIDT, ManycoreErrorKind, ManycoreSystem, SinkSourceDirection, SystemDimensionsT, WithID, UNSUPPORTED_PLATFORM
+    error::ManycoreError, BorderRouter, Borders, ChannelIDT, Core, Cores, Directions, Edge,
+    ElementIDT, LoadT, ManycoreErrorKind, ManycoreSystem, SinkSourceDirection, SystemDimensionsT,
+    WithID, UNSUPPORTED_PLATFORM,
 };
 
 /// An enum storing all supported routing algorithms.
@@ -12,22 +32,78 @@ pub enum RoutingAlgorithms {
     Observed,
     RowFirst,
     ColumnFirst,
+    FaultTolerant,
+    TableBased,
+    /// Routes each edge row first to a random intermediate core, then row first to its destination.
+    Valiant {
+        seed: u64,
+    },
+    /// Routes each edge either row first or column first, picked at random.
+    O1Turn {
+        seed: u64,
+    },
 }
 
 /// Array used to expose supported algorithms as a configurable field.
+/// Only algorithms that take no parameters and route any valid system are advertised.
 pub(crate) static SUPPORTED_ALGORITHMS: [RoutingAlgorithms; 3] = [
     RoutingAlgorithms::Observed,
     RoutingAlgorithms::RowFirst,
     RoutingAlgorithms::ColumnFirst,
 ];
 
+/// Every routing algorithm, randomised ones seeded with 0. [`RoutingAlgorithms::FaultTolerant`]
+/// fails when faults make an edge unreachable, and [`RoutingAlgorithms::TableBased`] needs
+/// routing tables on every router.
+pub static ROUTING_ALGORITHMS: [RoutingAlgorithms; 7] = [
+    RoutingAlgorithms::Observed,
+    RoutingAlgorithms::RowFirst,
+    RoutingAlgorithms::ColumnFirst,
+    RoutingAlgorithms::FaultTolerant,
+    RoutingAlgorithms::TableBased,
+    RoutingAlgorithms::Valiant { seed: 0 },
+    RoutingAlgorithms::O1Turn { seed: 0 },
+];
+
+impl RoutingAlgorithms {
+    /// Returns every routing algorithm in the order of [`ROUTING_ALGORITHMS`], randomised ones
+    /// once per seed in `seeds`.
+    pub fn all(seeds: &[u64]) -> Vec<Self> {
+        let mut algorithms = Vec::with_capacity(ROUTING_ALGORITHMS.len() + 2 * seeds.len());
+        for algorithm in &ROUTING_ALGORITHMS {
+            match algorithm {
+                RoutingAlgorithms::Valiant { .. } => algorithms.extend(
+                    seeds
+                        .iter()
+                        .map(|seed| RoutingAlgorithms::Valiant { seed: *seed }),
+                ),
+                RoutingAlgorithms::O1Turn { .. } => algorithms.extend(
+                    seeds
+                        .iter()
+                        .map(|seed| RoutingAlgorithms::O1Turn { seed: *seed }),
+                ),
+                _ => algorithms.push(algorithm.clone()),
+            }
+        }
+
+        algorithms
+    }
+}
+
+/// Directions in the order they are attempted by fault tolerant routing.
+/// Row moves come first so that a fault-free mesh is routed as [`RoutingAlgorithms::RowFirst`] would.
+static DETOUR_PREFERENCE: [Directions; 4] = [
+    Directions::North,
+    Directions::South,
+    Directions::West,
+    Directions::East,
+];
+
 #[derive(Debug)]
 /// Provides information for routing a task graph edge.
 struct EdgeRoutingInformation {
     /// The source core id.
     start_id: ElementIDT,
-    /// The source core column.
-    start_column: SystemDimensionsT,
     /// The destination core id.
     destination_id: ElementIDT,
     /// The current routing column.
@@ -39,15 +115,17 @@ struct EdgeRoutingInformation {
     /// The destination core row.
     destination_row: SystemDimensionsT,
     /// The edge cost.
-    communication_cost: u16,
+    communication_cost: LoadT,
     /// The source direction, if any.
     source_direction: Option<SinkSourceDirection>,
     /// The sink direction, if any.
     sink_direction: Option<SinkSourceDirection>,
+    /// The random decision taken for this edge, if the algorithm is randomised.
+    choice: Option<RouteChoice>,
 }
 
 /// Enum to differentiate type of routing packets.
-#[derive(Eq, Hash, PartialEq, Clone, Debug, PartialOrd, Ord)]
+#[derive(Serialize, Eq, Hash, PartialEq, Clone, Copy, Debug, PartialOrd, Ord)]
 pub enum RoutingType {
     OutputChannel,
     SourceChannel,
@@ -78,60 +156,131 @@ pub(crate) fn get_core(cores: &mut Cores, i: usize) -> Result<&mut Core, Manycor
 /// Type of a successfully genereated routing result map.
 pub type RoutingMap = HashMap<ElementIDT, BTreeMap<RoutingType, BTreeSet<Directions>>>;
 
-/// Utility function to add routing data to the routing result map.
-fn add_to_ret(key: ElementIDT, routing_type: RoutingType, direction: Directions, ret: &mut RoutingMap) {
-    ret.entry(key)
-        .or_insert(BTreeMap::default())
-        .entry(routing_type)
-        .or_insert(BTreeSet::default())
-        .insert(direction);
+/// Returns the ID of the core adjacent to `core_id` in the given direction, if any.
+pub(crate) fn neighbour(
+    core_id: ElementIDT,
+    direction: Directions,
+    columns_in_id_space: ElementIDT,
+    rows_in_id_space: ElementIDT,
+) -> Option<ElementIDT> {
+    let row = core_id / columns_in_id_space;
+    let column = core_id % columns_in_id_space;
+
+    match direction {
+        Directions::North if row > 0 => Some(core_id - columns_in_id_space),
+        Directions::South if row + 1 < rows_in_id_space => Some(core_id + columns_in_id_space),
+        Directions::West if column > 0 => Some(core_id - 1),
+        Directions::East if column + 1 < columns_in_id_space => Some(core_id + 1),
+        _ => None,
+    }
 }
 
-/// Utility function to add borders routing information to the routing result map.
-fn handle_borders(
-    cores: &mut Cores,
-    ret: &mut RoutingMap,
-    eri: &EdgeRoutingInformation,
-) -> Result<(), ManycoreError> {
-    // Was the task graph edge routed through a source?
-    if let Some(source_direction) = eri.source_direction.as_ref() {
-        // If so, we'll want to display load of the source channel. Add to map.
-        let direction = source_direction.into();
-        // This will never panic because of negative IDs as we sanitise them on parsing.
-        let start_idx = usize::try_from(eri.start_id).expect(UNSUPPORTED_PLATFORM);
-
-        add_to_ret(eri.start_id, RoutingType::SourceChannel, direction, ret);
-
-        // Output connections from sources are not part of the input XML.
-        // We must cumulatively track the load here.
-        get_core(cores, start_idx)?.add_source_load(eri.communication_cost, &direction)?;
+/// Determines if a packet can leave `core_id` in the given direction without
+/// crossing a faulty router or channel. Returns the next core ID if so.
+fn healthy_hop(
+    cores: &Cores,
+    core_id: ElementIDT,
+    direction: Directions,
+    columns_in_id_space: ElementIDT,
+    rows_in_id_space: ElementIDT,
+) -> Option<ElementIDT> {
+    let next = neighbour(core_id, direction, columns_in_id_space, rows_in_id_space)?;
+    let core = cores
+        .list()
+        .get(usize::try_from(core_id).expect(UNSUPPORTED_PLATFORM))?;
+    let next_core = cores
+        .list()
+        .get(usize::try_from(next).expect(UNSUPPORTED_PLATFORM))?;
+
+    let channel_faulty = core
+        .channels()
+        .channel()
+        .get(&direction)
+        .is_none_or(|channel| *channel.faulty());
+
+    if channel_faulty || *core.router().faulty() || *next_core.router().faulty() {
+        return None;
     }
 
-    // Was the task graph edge rrouted through a sink?
-    if let Some(sink_direction) = eri.sink_direction.as_ref() {
-        // If so, we'll want to display load of the sink channel. Add to map.
-        let direction = sink_direction.into();
-        // See panic note on start_idx.
-        let destination_idx = usize::try_from(eri.destination_id).expect(UNSUPPORTED_PLATFORM);
-
-        add_to_ret(
-            eri.destination_id,
-            RoutingType::OutputChannel,
-            direction,
-            ret,
-        );
-
-        // A sink incoming link is actually a core's outgoing channel.
-        // Cumulatively track the load on the channel.
-        // We do it here because sinks are not actually part of the inner
-        // algorithmically routable connections matrix.
-        // The routing algorithm will stop upon reaching the target core (column, row) pair.
-        get_core(cores, destination_idx)?
-            .channels_mut()
-            .add_to_load(eri.communication_cost, direction)?;
+    Some(next)
+}
+
+/// Computes a shortest path from `start_id` to `destination_id` that avoids faulty
+/// routers and channels. Returns the sequence of output directions, or [`None`] if
+/// the destination is unreachable.
+fn fault_free_path(
+    cores: &Cores,
+    start_id: ElementIDT,
+    destination_id: ElementIDT,
+    columns_in_id_space: ElementIDT,
+    rows_in_id_space: ElementIDT,
+) -> Option<Vec<Directions>> {
+    let router_faulty = |id: ElementIDT| {
+        cores
+            .list()
+            .get(usize::try_from(id).expect(UNSUPPORTED_PLATFORM))
+            .is_none_or(|core| *core.router().faulty())
+    };
+
+    if router_faulty(start_id) || router_faulty(destination_id) {
+        return None;
     }
 
-    Ok(())
+    // Breadth first search from the destination, walking channels backwards.
+    // Yields each core's hop distance from the destination.
+    let mut distances: HashMap<ElementIDT, usize> = HashMap::from([(destination_id, 0)]);
+    let mut queue = VecDeque::from([destination_id]);
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[&current];
+
+        for direction in DETOUR_PREFERENCE {
+            if let Some(previous) =
+                neighbour(current, direction, columns_in_id_space, rows_in_id_space)
+            {
+                if distances.contains_key(&previous) {
+                    continue;
+                }
+
+                // Can we travel from previous into current?
+                if healthy_hop(
+                    cores,
+                    previous,
+                    direction.opposite(),
+                    columns_in_id_space,
+                    rows_in_id_space,
+                )
+                .is_some()
+                {
+                    distances.insert(previous, distance + 1);
+                    queue.push_back(previous);
+                }
+            }
+        }
+    }
+
+    // Greedily walk down the distance gradient from the start.
+    let mut remaining = *distances.get(&start_id)?;
+    let mut current = start_id;
+    let mut path = Vec::with_capacity(remaining);
+    while remaining > 0 {
+        let (direction, next) = DETOUR_PREFERENCE.iter().find_map(|direction| {
+            let next = healthy_hop(
+                cores,
+                current,
+                *direction,
+                columns_in_id_space,
+                rows_in_id_space,
+            )?;
+
+            (distances.get(&next) == Some(&(remaining - 1))).then_some((*direction, next))
+        })?;
+
+        path.push(direction);
+        current = next;
+        remaining -= 1;
+    }
+
+    Some(path)
 }
 
 /// Determines if the provided task_id is mapped on an edge/border router. If so, what core is it connected to and in what direction.
@@ -155,7 +304,7 @@ fn border_task_id_to_core(borders: &Borders, task_id: u16) -> Option<(usize, Sin
 fn task_id_to_core<'a>(
     task_core_map: &HashMap<u16, usize>,
     task_id: u16,
-    borders: &mut Option<Borders>,
+    borders: &Option<Borders>,
     cores: &'a Cores,
 ) -> Result<(&'a Core, Option<SinkSourceDirection>), ManycoreError> {
     match task_core_map.get(&task_id) {
@@ -179,301 +328,449 @@ fn task_id_to_core<'a>(
     }
 }
 
-impl ManycoreSystem {
-    /// Calculates required routing information for the given task graph edge.
-    fn calculate_edge_routing_information(
-        cores: &Cores,
-        borders: &mut Option<Borders>,
-        task_core_map: &HashMap<u16, usize>,
-        edge: &Edge,
+impl EdgeRoutingInformation {
+    /// Calculates routing information between two cores of the routers matrix.
+    fn between(
+        start_id: ElementIDT,
+        destination_id: ElementIDT,
+        communication_cost: LoadT,
         columns_in_id_space: &ElementIDT,
-        rows_in_id_space: &ElementIDT,
-    ) -> Result<EdgeRoutingInformation, ManycoreError> {
-        // Retrieve core upon which source task is mapped.
-        // Will take care of mapping onto core if coming from source.
-        let (start, source) = task_id_to_core(task_core_map, *edge.from(), borders, cores)?;
-
-        // Retrieve core upon which destination task is mapped.
-        // Will take care of mapping onto core if coming from sink.
-        let (destination, sink) = task_id_to_core(task_core_map, *edge.to(), borders, cores)?;
-
-        let start_id = *start.id();
-        let destination_id = *destination.id();
-
+    ) -> Result<Self, ManycoreError> {
         // Workout where are we and where do we want to go in inner matrix.
         let current_column = SystemDimensionsT::try_from(start_id % columns_in_id_space)?;
-        let start_column = current_column.clone();
-        let current_row = SystemDimensionsT::try_from(start_id / rows_in_id_space)?;
+        // IDs are laid out row by row, so a core's row is given by how many full rows precede it.
+        let current_row = SystemDimensionsT::try_from(start_id / columns_in_id_space)?;
         let destination_column = SystemDimensionsT::try_from(destination_id % columns_in_id_space)?;
-        let destination_row = SystemDimensionsT::try_from(destination_id / rows_in_id_space)?;
+        let destination_row = SystemDimensionsT::try_from(destination_id / columns_in_id_space)?;
 
         Ok(EdgeRoutingInformation {
             start_id,
-            start_column,
             destination_id,
             current_column,
             current_row,
             destination_column,
             destination_row,
-            communication_cost: *edge.communication_cost(),
-            source_direction: source,
-            sink_direction: sink,
+            communication_cost,
+            source_direction: None,
+            sink_direction: None,
+            choice: None,
         })
     }
+}
 
-    /// RowFirst algorithm implementation.
-    fn row_first(&mut self) -> Result<RoutingMap, ManycoreError> {
-        let ManycoreSystem {
-            ref mut cores,
-            ref columns,
-            ref columns_in_id_space,
-            ref rows_in_id_space,
-            ref task_graph,
-            ref mut borders,
-            ref task_core_map,
-            ..
-        } = *self;
-
-        // Return value. Stores non-zero core-edge pairs.
-        let mut ret: RoutingMap = HashMap::new();
-
-        // For each edge in the task graph
-        for edge in task_graph.edges() {
-            let mut eri = ManycoreSystem::calculate_edge_routing_information(
-                cores,
-                borders,
-                task_core_map,
-                edge,
-                columns_in_id_space,
-                rows_in_id_space,
-            )?;
+/// Computes the output directions taken through the routers matrix by a dimension ordered algorithm.
+/// Rows are travelled first if `row_first` is set, columns otherwise.
+fn dimension_ordered_path(eri: &EdgeRoutingInformation, row_first: bool) -> Vec<Directions> {
+    let mut path = Vec::new();
+    let mut current_row = eri.current_row;
+    let mut current_column = eri.current_column;
+
+    loop {
+        let rows_pending = eri.destination_row != current_row;
+        let columns_pending = eri.destination_column != current_column;
+
+        if rows_pending && (row_first || !columns_pending) {
+            if current_row > eri.destination_row {
+                // Going up
+                path.push(Directions::North);
+                current_row -= 1;
+            } else {
+                // Going down
+                path.push(Directions::South);
+                current_row += 1;
+            }
+        } else if columns_pending {
+            if current_column > eri.destination_column {
+                // Going left
+                path.push(Directions::West);
+                current_column -= 1;
+            } else {
+                // Going right
+                path.push(Directions::East);
+                current_column += 1;
+            }
+        } else {
+            // We reached the destination
+            break;
+        }
+    }
 
-            handle_borders(cores, &mut ret, &eri)?;
+    path
+}
 
-            let mut current_idx = usize::try_from(eri.start_id).expect(UNSUPPORTED_PLATFORM);
-            let mut core;
+/// Wrapper function to generate a [`ManycoreErrorKind::RoutingError`] caused by a task graph edge that cannot be routed.
+fn unreachable_edge(edge: &Edge, algorithm: &RoutingAlgorithms) -> ManycoreError {
+    let reason = match algorithm {
+        RoutingAlgorithms::TableBased => "a router on the way has no routing table entry for it",
+        _ => "every path is blocked by faulty routers or channels",
+    };
 
-            // We must update every connection in the routers matrix
-            loop {
-                core = get_core(cores, current_idx)?;
-                let core_id = *core.id();
+    routing_error(format!(
+        "Edge {} -> {} is unreachable: {reason}.",
+        edge.from(),
+        edge.to()
+    ))
+}
 
-                let channels = core.channels_mut();
+impl ManycoreSystem {
+    /// Calculates required routing information for the given task graph edge, with tasks
+    /// allocated as in `task_core_map`.
+    fn edge_routing_information(
+        &self,
+        edge: &Edge,
+        task_core_map: &HashMap<u16, usize>,
+    ) -> Result<EdgeRoutingInformation, ManycoreError> {
+        // Retrieve core upon which source task is mapped.
+        // Will take care of mapping onto core if coming from source.
+        let (start, source) =
+            task_id_to_core(task_core_map, *edge.from(), &self.borders, &self.cores)?;
 
-                if eri.destination_row != eri.current_row {
-                    // Row first
-                    if eri.start_id > eri.destination_id {
-                        // Going up
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::North,
-                            &mut ret,
-                        );
+        // Retrieve core upon which destination task is mapped.
+        // Will take care of mapping onto core if coming from sink.
+        let (destination, sink) =
+            task_id_to_core(task_core_map, *edge.to(), &self.borders, &self.cores)?;
+
+        let mut eri = EdgeRoutingInformation::between(
+            *start.id(),
+            *destination.id(),
+            *edge.communication_cost(),
+            &self.columns_in_id_space,
+        )?;
+        eri.source_direction = source;
+        eri.sink_direction = sink;
+
+        Ok(eri)
+    }
 
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::North)?;
-                        current_idx -= usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row -= 1;
-                    } else {
-                        // Going down
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::South,
-                            &mut ret,
-                        );
+    /// Draws the random decision a randomised algorithm takes for the given stream. Each edge
+    /// is its own stream, so decisions do not depend on the order edges are routed in.
+    fn random_choice(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        stream: u64,
+    ) -> Result<Option<RouteChoice>, ManycoreError> {
+        match algorithm {
+            RoutingAlgorithms::Valiant { seed } => {
+                let number_of_cores = u64::try_from(self.cores.list().len())?;
+                let intermediate = SplitMix64::for_stream(*seed, stream).below(number_of_cores);
 
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::South)?;
-                        current_idx += usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row += 1;
+                Ok(Some(RouteChoice::Intermediate(ElementIDT::try_from(
+                    intermediate,
+                )?)))
+            }
+            RoutingAlgorithms::O1Turn { seed } => {
+                if SplitMix64::for_stream(*seed, stream).next_u64() & 1 == 0 {
+                    Ok(Some(RouteChoice::RowFirst))
+                } else {
+                    Ok(Some(RouteChoice::ColumnFirst))
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Computes the output directions taken through the routers matrix under the given algorithm.
+    /// Returns [`None`] if the algorithm cannot reach the destination.
+    fn edge_path(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        eri: &EdgeRoutingInformation,
+    ) -> Result<Option<Vec<Directions>>, ManycoreError> {
+        match algorithm {
+            RoutingAlgorithms::RowFirst => Ok(Some(dimension_ordered_path(eri, true))),
+            RoutingAlgorithms::ColumnFirst => Ok(Some(dimension_ordered_path(eri, false))),
+            RoutingAlgorithms::FaultTolerant => {
+                // A sink can only be reached through the destination's border channel.
+                if let Some(sink_direction) = eri.sink_direction.as_ref() {
+                    let sink_channel_faulty = self
+                        .cores
+                        .list()
+                        .get(usize::try_from(eri.destination_id).expect(UNSUPPORTED_PLATFORM))
+                        .and_then(|core| core.channels().channel().get(&sink_direction.into()))
+                        .is_none_or(|channel| *channel.faulty());
+
+                    if sink_channel_faulty {
+                        return Ok(None);
                     }
-                } else if eri.destination_column != eri.current_column {
-                    // Then column
-                    if eri.start_column > eri.destination_column {
-                        // Going left
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::West,
-                            &mut ret,
-                        );
+                }
 
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::West)?;
-                        current_idx -= 1;
-                        eri.current_column -= 1;
-                    } else {
-                        // Going right
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::East,
-                            &mut ret,
+                Ok(fault_free_path(
+                    &self.cores,
+                    eri.start_id,
+                    eri.destination_id,
+                    self.columns_in_id_space,
+                    self.rows_in_id_space,
+                ))
+            }
+            RoutingAlgorithms::TableBased => {
+                match self.table_path(eri.start_id, eri.destination_id) {
+                    Ok(path) => Ok(Some(path)),
+                    // Partial tables leave the destination unreachable, broken ones are errors.
+                    Err(RoutingTableIssue::MissingEntry { .. }) => Ok(None),
+                    Err(issue) => Err(routing_error(issue.to_string())),
+                }
+            }
+            RoutingAlgorithms::Valiant { .. } | RoutingAlgorithms::O1Turn { .. } => {
+                match eri.choice {
+                    Some(RouteChoice::Intermediate(intermediate_id)) => {
+                        let mut path = dimension_ordered_path(
+                            &EdgeRoutingInformation::between(
+                                eri.start_id,
+                                intermediate_id,
+                                eri.communication_cost,
+                                &self.columns_in_id_space,
+                            )?,
+                            true,
                         );
-
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::East)?;
-                        current_idx += 1;
-                        eri.current_column += 1;
+                        path.extend(dimension_ordered_path(
+                            &EdgeRoutingInformation::between(
+                                intermediate_id,
+                                eri.destination_id,
+                                eri.communication_cost,
+                                &self.columns_in_id_space,
+                            )?,
+                            true,
+                        ));
+
+                        Ok(Some(path))
                     }
-                } else {
-                    // We reached the destination
-                    break;
+                    Some(RouteChoice::RowFirst) => Ok(Some(dimension_ordered_path(eri, true))),
+                    Some(RouteChoice::ColumnFirst) => Ok(Some(dimension_ordered_path(eri, false))),
+                    None => Err(routing_error(format!(
+                        "No random decision was drawn for {algorithm:?} routing."
+                    ))),
                 }
             }
+            RoutingAlgorithms::Observed => Err(routing_error(
+                "Observed routing mirrors Channels information and does not follow paths.".into(),
+            )),
         }
+    }
 
-        Ok(ret)
+    /// Converts a path of output directions starting from `start_id` into the sequence of
+    /// traversed channels.
+    fn path_channels(
+        &self,
+        start_id: ElementIDT,
+        path: &[Directions],
+    ) -> Result<Vec<ChannelIDT>, ManycoreError> {
+        let mut current_id = start_id;
+
+        path.iter()
+            .map(|direction| {
+                let channel = (current_id, *direction);
+                current_id = neighbour(
+                    current_id,
+                    *direction,
+                    self.columns_in_id_space,
+                    self.rows_in_id_space,
+                )
+                .ok_or(routing_error(format!(
+                    "Routing left the matrix going {direction} from core {current_id}."
+                )))?;
+
+                Ok(channel)
+            })
+            .collect()
     }
 
-    /// ColumnFirst algorithm implementation.
-    fn column_first(&mut self) -> Result<RoutingMap, ManycoreError> {
-        let ManycoreSystem {
-            ref mut cores,
-            ref columns,
-            ref columns_in_id_space,
-            ref rows_in_id_space,
-            ref task_graph,
-            ref mut borders,
-            ref task_core_map,
-            ..
-        } = *self;
-
-        // Return value. Stores non-zero core-edge pairs.
-        let mut ret: RoutingMap = HashMap::new();
-
-        // For each edge in the task graph
-        for edge in task_graph.edges() {
-            let mut eri = ManycoreSystem::calculate_edge_routing_information(
-                cores,
-                borders,
-                task_core_map,
-                edge,
-                columns_in_id_space,
-                rows_in_id_space,
-            )?;
+    /// Works out the routing information and traversed channels of the task graph edge at
+    /// `edge_index` under the given algorithm, with tasks allocated as in `task_core_map`.
+    fn edge_channels(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        edge_index: usize,
+        task_core_map: &HashMap<u16, usize>,
+    ) -> Result<(EdgeRoutingInformation, Vec<ChannelIDT>), ManycoreError> {
+        let edge = self
+            .task_graph
+            .edges()
+            .get(edge_index)
+            .ok_or(routing_error(format!(
+                "Task graph has no edge with index {edge_index}."
+            )))?;
+
+        let mut eri = self.edge_routing_information(edge, task_core_map)?;
+        eri.choice = self.random_choice(algorithm, u64::try_from(edge_index)?)?;
+        let path = self
+            .edge_path(algorithm, &eri)?
+            .ok_or_else(|| unreachable_edge(edge, algorithm))?;
+        let channels = self.path_channels(eri.start_id, &path)?;
+
+        Ok((eri, channels))
+    }
 
-            handle_borders(cores, &mut ret, &eri)?;
+    /// Works out every task graph edge's routing information and traversed channels under the
+    /// given algorithm, in task graph edge order.
+    fn task_graph_routes(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<Vec<(EdgeRoutingInformation, Vec<ChannelIDT>)>, ManycoreError> {
+        (0..self.task_graph.edges().len())
+            .map(|edge_index| self.edge_channels(algorithm, edge_index, &self.task_core_map))
+            .collect()
+    }
 
-            let mut current_idx = usize::try_from(eri.start_id).expect(UNSUPPORTED_PLATFORM);
-            let mut core;
+    /// Works out the hop sequence followed by the task graph edge at `edge_index` under the
+    /// given algorithm, border hops included.
+    pub(crate) fn edge_route(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        edge_index: usize,
+    ) -> Result<EdgeRoute, ManycoreError> {
+        self.allocated_edge_route(algorithm, edge_index, &self.task_core_map)
+    }
 
-            // We must update every connection in the routers matrix
-            loop {
-                core = get_core(cores, current_idx)?;
-                let core_id = *core.id();
+    /// Same as [`ManycoreSystem::edge_route`], with tasks allocated as in `task_core_map`
+    /// rather than as in the system.
+    pub(crate) fn allocated_edge_route(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        edge_index: usize,
+        task_core_map: &HashMap<u16, usize>,
+    ) -> Result<EdgeRoute, ManycoreError> {
+        let (eri, channels) = self.edge_channels(algorithm, edge_index, task_core_map)?;
+
+        let mut hops = Vec::with_capacity(channels.len() + 2);
+        // Output connections from sources are not part of the input XML, the source port
+        // is tracked as a hop of its own.
+        if let Some(source_direction) = eri.source_direction.as_ref() {
+            hops.push(RoutingHop::new(
+                eri.start_id,
+                RoutingType::SourceChannel,
+                source_direction.into(),
+            ));
+        }
 
-                let channels = core.channels_mut();
+        hops.extend(channels.into_iter().map(|(core_id, direction)| {
+            RoutingHop::new(core_id, RoutingType::OutputChannel, direction)
+        }));
+
+        // A sink incoming link is actually the destination core's outgoing channel.
+        // Sinks are not part of the inner algorithmically routable connections matrix,
+        // so the channel is appended once the destination core is reached.
+        if let Some(sink_direction) = eri.sink_direction.as_ref() {
+            hops.push(RoutingHop::new(
+                eri.destination_id,
+                RoutingType::OutputChannel,
+                sink_direction.into(),
+            ));
+        }
 
-                if eri.destination_column != eri.current_column {
-                    // Column first
-                    if eri.start_column > eri.destination_column {
-                        // Going left
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::West,
-                            &mut ret,
-                        );
+        let edge = &self.task_graph.edges()[edge_index];
+        Ok(EdgeRoute::new(
+            edge_index,
+            *edge.from(),
+            *edge.to(),
+            eri.communication_cost,
+            hops,
+            eri.choice,
+        ))
+    }
 
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::West)?;
-                        current_idx -= 1;
-                        eri.current_column -= 1;
-                    } else {
-                        // Going right
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::East,
-                            &mut ret,
-                        );
+    /// Returns the channels traversed by every task graph edge under the given algorithm, in
+    /// task graph edge order. Channels into sinks are included.
+    pub(crate) fn task_graph_channel_paths(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<Vec<Vec<ChannelIDT>>, ManycoreError> {
+        Ok(self
+            .task_graph_routes(algorithm)?
+            .into_iter()
+            .map(|(eri, mut channels)| {
+                if let Some(sink_direction) = eri.sink_direction.as_ref() {
+                    channels.push((eri.destination_id, sink_direction.into()));
+                }
 
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::East)?;
-                        current_idx += 1;
-                        eri.current_column += 1;
-                    }
-                } else if eri.destination_row != eri.current_row {
-                    // Then row
-
-                    if eri.start_id > eri.destination_id {
-                        // Going up
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::North,
-                            &mut ret,
-                        );
+                channels
+            })
+            .collect())
+    }
 
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::North)?;
-                        current_idx -= usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row -= 1;
-                    } else {
-                        // Going down
-                        add_to_ret(
-                            core_id,
-                            RoutingType::OutputChannel,
-                            Directions::South,
-                            &mut ret,
-                        );
+    /// Returns the channels traversed from `start_id` to `destination_id` under the given
+    /// algorithm, or [`None`] if the algorithm cannot reach the destination.
+    pub(crate) fn core_to_core_channel_path(
+        &self,
+        algorithm: &RoutingAlgorithms,
+        start_id: ElementIDT,
+        destination_id: ElementIDT,
+    ) -> Result<Option<Vec<ChannelIDT>>, ManycoreError> {
+        let mut eri = EdgeRoutingInformation::between(
+            start_id,
+            destination_id,
+            0,
+            &self.columns_in_id_space,
+        )?;
+        // Core pairs draw from their own streams, apart from task graph edge indices.
+        let stream = (1 << 32) | (u64::from(start_id) << 16) | u64::from(destination_id);
+        eri.choice = self.random_choice(algorithm, stream)?;
+
+        match self.edge_path(algorithm, &eri)? {
+            Some(path) => Ok(Some(self.path_channels(start_id, &path)?)),
+            None => Ok(None),
+        }
+    }
 
-                        let _ = channels.add_to_load(eri.communication_cost, Directions::South)?;
-                        current_idx += usize::try_from(*columns).expect(UNSUPPORTED_PLATFORM);
-                        eri.current_row += 1;
-                    }
-                } else {
-                    // We reached the destination
-                    break;
-                }
-            }
+    /// Routes every task graph edge along the path chosen by the given algorithm.
+    fn route_task_graph(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<RoutingResult, ManycoreError> {
+        // Work out every edge's route before touching any load.
+        let routed = (0..self.task_graph.edges().len())
+            .map(|edge_index| self.edge_route(algorithm, edge_index))
+            .collect::<Result<Vec<EdgeRoute>, ManycoreError>>()?;
+
+        let mut result = RoutingResult::new(algorithm.clone());
+        for route in &routed {
+            result.add_route(&self.cores, route)?;
         }
+        result.set_edge_routes(EdgeRoutes::new(routed));
 
-        Ok(ret)
+        Ok(result)
     }
 
     /// Observed route implementation. Mirrors Channels information.
-    fn observed_route(&mut self) -> Result<RoutingMap, ManycoreError> {
-        let ManycoreSystem {
-            ref mut cores,
-            ref mut borders,
-            ..
-        } = *self;
+    fn observed_route(&self) -> Result<RoutingResult, ManycoreError> {
+        let mut result = RoutingResult::new(RoutingAlgorithms::Observed);
 
-        let mut ret: RoutingMap = HashMap::new();
-
-        let mut core;
         // Copy all core loads over
-        for i in 0..cores.list().len() {
-            core = get_core(cores, i)?;
-            let core_id = *core.id();
-            for (direction, channel) in core.channels_mut().channel_mut() {
+        for core in self.cores.list() {
+            for (direction, channel) in core.channels().channel() {
                 let packets = *channel.actual_com_cost();
                 if packets != 0 {
-                    add_to_ret(core_id, RoutingType::OutputChannel, *direction, &mut ret);
-
-                    channel.add_to_load(packets);
+                    result.add_channel_load(&self.cores, (*core.id(), *direction), packets)?;
                 }
             }
         }
 
         // Copy all source loads over
-        if let Some(borders) = borders {
+        if let Some(borders) = self.borders.as_ref() {
             for source in borders.sources().values() {
                 if let Some(actual_com_cost) = source.actual_com_cost() {
                     if *actual_com_cost != 0 {
-                        let direction = Directions::from(source.direction());
+                        let core_id = ElementIDT::try_from(*source.core_id())?;
 
-                        let core = get_core(cores, *source.core_id())?;
-                        core.add_source_load(*actual_com_cost, &direction)?;
-
-                        add_to_ret(*core.id(), RoutingType::SourceChannel, direction, &mut ret);
+                        result.add_source_load(
+                            &self.cores,
+                            (core_id, Directions::from(source.direction())),
+                            *actual_com_cost,
+                        )?;
                     }
                 }
             }
         }
 
-        Ok(ret)
+        Ok(result)
     }
 
-    /// Clears all channel loads.
+    /// Clears all channel loads and edge routes.
     fn clear_channels(&mut self) {
+        self.edge_routes = EdgeRoutes::default();
+        self.routing_algorithm = None;
+        self.routed_multicast = false;
+        self.energy = None;
+
         // Zero out all links costs
         self.cores_mut().list_mut().iter_mut().for_each(|c| {
             // Channel loads
@@ -483,14 +780,51 @@ impl ManycoreSystem {
         });
     }
 
-    /// Performs routing according to the requested algorithm.
-    pub fn route(&mut self, algorithm: &RoutingAlgorithms) -> Result<RoutingMap, ManycoreError> {
-        self.clear_channels();
-
+    /// Computes routing according to the requested algorithm without modifying the system.
+    /// Several results can be computed side by side, or from several threads.
+    pub fn compute_routing(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<RoutingResult, ManycoreError> {
         match algorithm {
-            RoutingAlgorithms::ColumnFirst => self.column_first(),
-            RoutingAlgorithms::RowFirst => self.row_first(),
             RoutingAlgorithms::Observed => self.observed_route(),
+            _ => self.route_task_graph(algorithm),
+        }
+    }
+
+    /// Replaces the system's channel loads, source loads and edge routes with the ones
+    /// stored in the provided [`RoutingResult`].
+    pub fn apply_routing_result(&mut self, result: &RoutingResult) -> Result<(), ManycoreError> {
+        self.clear_channels();
+
+        for ((core_id, direction), load) in result.channel_loads() {
+            get_core(
+                &mut self.cores,
+                usize::try_from(*core_id).expect(UNSUPPORTED_PLATFORM),
+            )?
+            .channels_mut()
+            .add_to_load(*load, *direction)?;
         }
+
+        for ((core_id, direction), load) in result.source_loads() {
+            get_core(
+                &mut self.cores,
+                usize::try_from(*core_id).expect(UNSUPPORTED_PLATFORM),
+            )?
+            .add_source_load(*load, direction)?;
+        }
+
+        self.edge_routes = result.edge_routes().clone();
+        self.routing_algorithm = Some(result.algorithm().clone());
+
+        Ok(())
+    }
+
+    /// Performs routing according to the requested algorithm and stores the outcome in the system.
+    pub fn route(&mut self, algorithm: &RoutingAlgorithms) -> Result<RoutingMap, ManycoreError> {
+        let result = self.compute_routing(algorithm)?;
+        self.apply_routing_result(&result)?;
+
+        Ok(result.routing_map().clone())
     }
 }
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{error::ManycoreError, routing_error, Directions, ElementIDT, LoadT, RoutingType};
+
+#[cfg(doc)]
+use crate::{Channel, Edge};
+
+/// A single hop of a routed task graph [`Edge`]: a [`Channel`] or source port the edge traverses.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct RoutingHop {
+    /// The ID of the core the hop belongs to.
+    core_id: ElementIDT,
+    /// Whether this is an output channel or a source channel.
+    routing_type: RoutingType,
+    /// The hop direction.
+    direction: Directions,
+}
+
+impl RoutingHop {
+    /// Instantiates a new [`RoutingHop`] instance.
+    pub fn new(core_id: ElementIDT, routing_type: RoutingType, direction: Directions) -> Self {
+        Self {
+            core_id,
+            routing_type,
+            direction,
+        }
+    }
+}
+
+/// The random decision a randomised algorithm took for a route.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum RouteChoice {
+    /// Valiant routing went through the core with the given ID.
+    Intermediate(ElementIDT),
+    /// O1TURN routing travelled rows first.
+    RowFirst,
+    /// O1TURN routing travelled columns first.
+    ColumnFirst,
+}
+
+/// The full hop sequence followed by a task graph [`Edge`].
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct EdgeRoute {
+    /// Index of the edge in the task graph.
+    edge_index: usize,
+    /// Edge source task.
+    from: u16,
+    /// Edge destination task.
+    to: u16,
+    /// Edge cost.
+    communication_cost: LoadT,
+    /// Hops in traversal order, including source and sink border hops.
+    hops: Vec<RoutingHop>,
+    /// The random decision taken for this edge, if the algorithm is randomised.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    choice: Option<RouteChoice>,
+}
+
+impl EdgeRoute {
+    /// Instantiates a new [`EdgeRoute`] instance.
+    pub(crate) fn new(
+        edge_index: usize,
+        from: u16,
+        to: u16,
+        communication_cost: LoadT,
+        hops: Vec<RoutingHop>,
+        choice: Option<RouteChoice>,
+    ) -> Self {
+        Self {
+            edge_index,
+            from,
+            to,
+            communication_cost,
+            hops,
+            choice,
+        }
+    }
+
+    /// Load the edge puts on each hop it traverses, once per crossing.
+    pub(crate) fn hop_loads(&self) -> Result<BTreeMap<RoutingHop, LoadT>, ManycoreError> {
+        let mut loads: BTreeMap<RoutingHop, LoadT> = BTreeMap::new();
+
+        for hop in &self.hops {
+            let load = loads.entry(*hop).or_default();
+            *load = load
+                .checked_add(self.communication_cost)
+                .ok_or(routing_error(format!(
+                    "Load from task {} to task {} overflowed.",
+                    self.from, self.to
+                )))?;
+        }
+
+        Ok(loads)
+    }
+}
+
+/// The routes of every task graph [`Edge`] produced by a routing run, along with a
+/// reverse index of the edges traversing each hop.
+#[derive(Serialize, Debug, PartialEq, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct EdgeRoutes {
+    /// Routes in task graph edge order.
+    routes: Vec<EdgeRoute>,
+    /// Maps each hop to the indices (in `routes`) of the edges traversing it.
+    #[serde(skip)]
+    index: BTreeMap<RoutingHop, Vec<usize>>,
+}
+
+impl EdgeRoutes {
+    /// Builds the reverse index for the provided routes.
+    pub(crate) fn new(routes: Vec<EdgeRoute>) -> Self {
+        let mut index: BTreeMap<RoutingHop, Vec<usize>> = BTreeMap::new();
+
+        for (i, route) in routes.iter().enumerate() {
+            for hop in route.hops() {
+                let edges = index.entry(*hop).or_default();
+
+                // An edge may only be listed once per hop.
+                if edges.last() != Some(&i) {
+                    edges.push(i);
+                }
+            }
+        }
+
+        Self { routes, index }
+    }
+
+    /// Replaces the route of the same task graph edge as `route`, keeping the reverse index
+    /// up to date. Returns the replaced route, or [`None`] if the edge had no route.
+    pub(crate) fn replace(&mut self, route: EdgeRoute) -> Option<EdgeRoute> {
+        let position = self
+            .routes
+            .iter()
+            .position(|r| r.edge_index == route.edge_index)?;
+
+        for hop in &self.routes[position].hops {
+            if let Some(edges) = self.index.get_mut(hop) {
+                edges.retain(|i| *i != position);
+
+                if edges.is_empty() {
+                    self.index.remove(hop);
+                }
+            }
+        }
+
+        for hop in &route.hops {
+            let edges = self.index.entry(*hop).or_default();
+            if let Err(insert_at) = edges.binary_search(&position) {
+                edges.insert(insert_at, position);
+            }
+        }
+
+        Some(std::mem::replace(&mut self.routes[position], route))
+    }
+
+    /// Returns the routes of all edges traversing the given hop.
+    pub fn traversing(&self, hop: &RoutingHop) -> Vec<&EdgeRoute> {
+        self.index
+            .get(hop)
+            .map(|edges| edges.iter().map(|i| &self.routes[*i]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the route of the task graph edge at the given index, if it was routed.
+    pub fn route_of(&self, edge_index: usize) -> Option<&EdgeRoute> {
+        self.routes
+            .iter()
+            .find(|route| *route.edge_index() == edge_index)
+    }
+}
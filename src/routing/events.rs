@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, utils::loads::serialise_channel_loads, ChannelIDT,
+    Directions, ElementIDT, LoadT, ManycoreSystem, MulticastRouting, RoutingAlgorithms, RoutingHop,
+    RoutingResult, RoutingType,
+};
+
+#[cfg(doc)]
+use crate::{Channel, Edge};
+
+/// A single step of a routing run, as replayed for animation.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum RoutingEvent {
+    /// Routing of a task graph [`Edge`] begins.
+    #[serde(rename_all = "camelCase")]
+    EdgeStarted {
+        edge_index: usize,
+        from: u16,
+        to: u16,
+        communication_cost: LoadT,
+    },
+    /// The edge's traffic enters the mesh through a source port. `load` is the port's
+    /// cumulative load so far.
+    #[serde(rename_all = "camelCase")]
+    SourceInjection {
+        edge_index: usize,
+        core_id: ElementIDT,
+        direction: Directions,
+        load: LoadT,
+    },
+    /// The edge's traffic leaves a core through a [`Channel`]. `load` is the channel's
+    /// cumulative load so far.
+    #[serde(rename_all = "camelCase")]
+    Hop {
+        edge_index: usize,
+        core_id: ElementIDT,
+        direction: Directions,
+        load: LoadT,
+    },
+    /// The edge's traffic leaves the mesh into a sink. `load` is the sink channel's
+    /// cumulative load so far.
+    #[serde(rename_all = "camelCase")]
+    SinkEjection {
+        edge_index: usize,
+        core_id: ElementIDT,
+        direction: Directions,
+        load: LoadT,
+    },
+    /// Routing of the edge is complete.
+    #[serde(rename_all = "camelCase")]
+    EdgeFinished { edge_index: usize },
+}
+
+/// Loads reached at some point of a routing run.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Default, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct RoutingSnapshot {
+    /// Load on each [`Channel`] that carried traffic so far.
+    #[serde(serialize_with = "serialise_channel_loads")]
+    channel_loads: BTreeMap<ChannelIDT, LoadT>,
+    /// Load on each source port that carried traffic so far.
+    #[serde(serialize_with = "serialise_channel_loads")]
+    source_loads: BTreeMap<ChannelIDT, LoadT>,
+}
+
+/// The ordered events of a routing run. Can be iterated any number of times, and
+/// scrubbed to any step.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct RoutingEvents {
+    /// Events in the order they occur.
+    events: Vec<RoutingEvent>,
+}
+
+impl RoutingEvents {
+    /// Iterates over the events from the start of the run.
+    pub fn iter(&self) -> std::slice::Iter<'_, RoutingEvent> {
+        self.events.iter()
+    }
+
+    /// Number of events in the run.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Determines if the run has no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the event at the given step, if any.
+    pub fn get(&self, step: usize) -> Option<&RoutingEvent> {
+        self.events.get(step)
+    }
+
+    /// Returns the loads reached once the first `step` events have occurred.
+    /// A step past the end of the run yields the final loads.
+    pub fn snapshot(&self, step: usize) -> RoutingSnapshot {
+        let mut snapshot = RoutingSnapshot::default();
+
+        for event in self.events.iter().take(step) {
+            match event {
+                RoutingEvent::SourceInjection {
+                    core_id,
+                    direction,
+                    load,
+                    ..
+                } => {
+                    snapshot.source_loads.insert((*core_id, *direction), *load);
+                }
+                RoutingEvent::Hop {
+                    core_id,
+                    direction,
+                    load,
+                    ..
+                }
+                | RoutingEvent::SinkEjection {
+                    core_id,
+                    direction,
+                    load,
+                    ..
+                } => {
+                    snapshot.channel_loads.insert((*core_id, *direction), *load);
+                }
+                RoutingEvent::EdgeStarted { .. } | RoutingEvent::EdgeFinished { .. } => {}
+            }
+        }
+
+        snapshot
+    }
+}
+
+impl<'a> IntoIterator for &'a RoutingEvents {
+    type Item = &'a RoutingEvent;
+    type IntoIter = std::slice::Iter<'a, RoutingEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl ManycoreSystem {
+    /// Replays the edge routes of a [`RoutingResult`] computed on this system as a sequence
+    /// of events. Edges are replayed one after the other, in task graph edge order.
+    ///
+    /// Results whose loads are not the sum of their edge routes, such as observed or
+    /// multicast ones, are rejected. Multicast runs are replayed with
+    /// [`ManycoreSystem::multicast_routing_events`].
+    pub fn routing_events(&self, result: &RoutingResult) -> Result<RoutingEvents, ManycoreError> {
+        self.replay(result, false)
+    }
+
+    /// Replays the multicast loads of a [`MulticastRouting`] computed on this system as a
+    /// sequence of events. Edges are replayed one after the other, in task graph edge order.
+    /// A hop is only charged the amount an edge raises its source task's tree cost by,
+    /// counting every crossing of the hop by the edge, so the final loads are the multicast
+    /// ones.
+    pub fn multicast_routing_events(
+        &self,
+        routing: &MulticastRouting,
+    ) -> Result<RoutingEvents, ManycoreError> {
+        self.replay(routing.multicast(), true)
+    }
+
+    /// Replays a result's edge routes, charging each hop once per source task tree if
+    /// `multicast` is set, and checks the replayed loads match the result's.
+    fn replay(
+        &self,
+        result: &RoutingResult,
+        multicast: bool,
+    ) -> Result<RoutingEvents, ManycoreError> {
+        if *result.algorithm() == RoutingAlgorithms::Observed {
+            return Err(routing_error(
+                "Observed results carry no edge routes to replay.".into(),
+            ));
+        }
+
+        let mut channel_loads: BTreeMap<ChannelIDT, LoadT> = BTreeMap::new();
+        let mut source_loads: BTreeMap<ChannelIDT, LoadT> = BTreeMap::new();
+        // Source task -> hop -> cost charged on the hop so far.
+        let mut trees: BTreeMap<u16, BTreeMap<RoutingHop, LoadT>> = BTreeMap::new();
+        let mut events = Vec::new();
+
+        for route in result.edge_routes().routes() {
+            let edge_index = *route.edge_index();
+            let cost = *route.communication_cost();
+            let into_sink = self
+                .borders
+                .as_ref()
+                .is_some_and(|borders| borders.sinks().contains_key(route.to()));
+
+            events.push(RoutingEvent::EdgeStarted {
+                edge_index,
+                from: *route.from(),
+                to: *route.to(),
+                communication_cost: cost,
+            });
+
+            // Load the edge has put on each hop so far, a hop may be crossed more than once.
+            let mut crossed: BTreeMap<RoutingHop, LoadT> = BTreeMap::new();
+            let last = route.hops().len().saturating_sub(1);
+            for (i, hop) in route.hops().iter().enumerate() {
+                let core_id = *hop.core_id();
+                let direction = *hop.direction();
+                let (loads, port) = match hop.routing_type() {
+                    RoutingType::OutputChannel => (&mut channel_loads, "channel"),
+                    RoutingType::SourceChannel => (&mut source_loads, "source channel"),
+                };
+                let overflow = || {
+                    routing_error(format!(
+                        "Load on the {direction} {port} of Core with ID {core_id} overflowed."
+                    ))
+                };
+
+                let charged = if multicast {
+                    let demand = crossed.entry(*hop).or_default();
+                    *demand = demand.checked_add(cost).ok_or_else(overflow)?;
+                    let charged = trees
+                        .entry(*route.from())
+                        .or_default()
+                        .entry(*hop)
+                        .or_default();
+                    let raise = demand.saturating_sub(*charged);
+                    *charged = (*charged).max(*demand);
+
+                    raise
+                } else {
+                    cost
+                };
+
+                let load = loads.entry((core_id, direction)).or_default();
+                *load = load.checked_add(charged).ok_or_else(overflow)?;
+                let load = *load;
+
+                events.push(match hop.routing_type() {
+                    RoutingType::SourceChannel => RoutingEvent::SourceInjection {
+                        edge_index,
+                        core_id,
+                        direction,
+                        load,
+                    },
+                    RoutingType::OutputChannel if into_sink && i == last => {
+                        RoutingEvent::SinkEjection {
+                            edge_index,
+                            core_id,
+                            direction,
+                            load,
+                        }
+                    }
+                    RoutingType::OutputChannel => RoutingEvent::Hop {
+                        edge_index,
+                        core_id,
+                        direction,
+                        load,
+                    },
+                });
+            }
+
+            events.push(RoutingEvent::EdgeFinished { edge_index });
+        }
+
+        if channel_loads != *result.channel_loads() || source_loads != *result.source_loads() {
+            return Err(routing_error(
+                "Replayed loads do not match the result's, was it computed on this system?".into(),
+            ));
+        }
+
+        Ok(RoutingEvents { events })
+    }
+}
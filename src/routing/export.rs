@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use getset::Getters;
+use quick_xml::DeError;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, Directions, ElementIDT, ManycoreSystem, RoutingAlgorithms,
+    RoutingTable, RoutingTableEntry, WithID,
+};
+
+/// The output port every router uses for every destination under a given algorithm.
+/// A router's own ID is delivered locally and has no entry, as do destinations the
+/// algorithm cannot reach.
+#[derive(Debug, PartialEq, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct RoutingTables {
+    /// The algorithm the tables were generated for.
+    algorithm: RoutingAlgorithms,
+    /// Map of router ID to a map of destination core ID to output direction.
+    next_hops: BTreeMap<ElementIDT, BTreeMap<ElementIDT, Directions>>,
+}
+
+/// XML representation of a single router's table.
+#[derive(Serialize)]
+struct RouterTableXml {
+    #[serde(rename = "@id")]
+    id: ElementIDT,
+    #[serde(rename = "RoutingTable")]
+    routing_table: RoutingTable,
+}
+
+/// XML representation of [`RoutingTables`].
+#[derive(Serialize)]
+struct RoutingTablesXml<'a> {
+    #[serde(rename = "@algorithm")]
+    algorithm: &'a RoutingAlgorithms,
+    #[serde(rename = "Router")]
+    routers: Vec<RouterTableXml>,
+}
+
+impl RoutingTables {
+    /// Returns the output direction `router_id` forwards `destination_id` to, if any.
+    pub fn next_hop(
+        &self,
+        router_id: ElementIDT,
+        destination_id: ElementIDT,
+    ) -> Option<Directions> {
+        self.next_hops
+            .get(&router_id)
+            .and_then(|hops| hops.get(&destination_id))
+            .copied()
+    }
+
+    /// Returns the [`RoutingTable`] of the given router, with consecutive destinations
+    /// sharing an output direction merged into a single range entry.
+    pub fn routing_table(&self, router_id: ElementIDT) -> Option<RoutingTable> {
+        let hops = self.next_hops.get(&router_id)?;
+
+        let mut entries: Vec<RoutingTableEntry> = Vec::new();
+        for (destination, direction) in hops {
+            match entries.last_mut() {
+                Some(last)
+                    if last.direction() == direction
+                        && last.to().unwrap_or(*last.from()) + 1 == *destination =>
+                {
+                    *last = RoutingTableEntry::new(*last.from(), Some(*destination), *direction);
+                }
+                _ => entries.push(RoutingTableEntry::new(*destination, None, *direction)),
+            }
+        }
+
+        Some(RoutingTable::new(entries))
+    }
+
+    /// Serialises the tables as XML. Each router is listed as a `<Router>` element holding
+    /// a `<RoutingTable>` in the same format accepted within the system XML.
+    pub fn to_xml(&self) -> Result<String, DeError> {
+        let xml = RoutingTablesXml {
+            algorithm: &self.algorithm,
+            routers: self
+                .next_hops
+                .keys()
+                .filter_map(|id| {
+                    Some(RouterTableXml {
+                        id: *id,
+                        routing_table: self.routing_table(*id)?,
+                    })
+                })
+                .collect(),
+        };
+
+        let mut buf = String::new();
+        let mut serialiser = quick_xml::se::Serializer::with_root(&mut buf, Some("RoutingTables"))?;
+        serialiser.indent(' ', 4);
+        serialiser.set_quote_level(quick_xml::se::QuoteLevel::Minimal);
+
+        xml.serialize(serialiser)?;
+
+        Ok(buf)
+    }
+
+    /// Serialises the tables as CSV, one `router,destination,direction` row per entry.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("router,destination,direction\n");
+
+        for (router_id, hops) in &self.next_hops {
+            for (destination_id, direction) in hops {
+                csv.push_str(&format!("{router_id},{destination_id},{direction}\n"));
+            }
+        }
+
+        csv
+    }
+}
+
+impl ManycoreSystem {
+    /// Generates the routing table of every router for every destination under the given
+    /// algorithm. Destinations the algorithm cannot reach, such as those partial routing
+    /// tables have no entry for, are omitted.
+    pub fn routing_tables(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<RoutingTables, ManycoreError> {
+        // Randomised algorithms decide per packet, a router's output depends on more than
+        // the destination.
+        if let RoutingAlgorithms::Valiant { .. } | RoutingAlgorithms::O1Turn { .. } = algorithm {
+            return Err(routing_error(format!(
+                "{algorithm:?} routing cannot be expressed as routing tables."
+            )));
+        }
+
+        let core_ids: Vec<ElementIDT> = self.cores.list().iter().map(|core| *core.id()).collect();
+
+        let mut next_hops = BTreeMap::new();
+        for router_id in core_ids.iter().copied() {
+            let mut hops = BTreeMap::new();
+
+            for destination_id in core_ids.iter().copied().filter(|d| *d != router_id) {
+                if let Some((_, direction)) = self
+                    .core_to_core_channel_path(algorithm, router_id, destination_id)?
+                    .and_then(|path| path.first().copied())
+                {
+                    hops.insert(destination_id, direction);
+                }
+            }
+
+            next_hops.insert(router_id, hops);
+        }
+
+        Ok(RoutingTables {
+            algorithm: algorithm.clone(),
+            next_hops,
+        })
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    error::ManycoreError, get_core, routing_error, EdgeRoute, LoadT, ManycoreSystem,
+    RoutingAlgorithms, RoutingHop, RoutingType,
+};
+
+impl ManycoreSystem {
+    /// Returns the load currently on a hop.
+    fn hop_load(&self, hop: &RoutingHop) -> Result<LoadT, ManycoreError> {
+        match hop.routing_type() {
+            RoutingType::OutputChannel => Ok(*self
+                .channel(*hop.core_id(), *hop.direction())?
+                .current_load()),
+            RoutingType::SourceChannel => Ok(self
+                .cores
+                .list()
+                .get(usize::from(*hop.core_id()))
+                .and_then(|core| core.source_loads().as_ref())
+                .and_then(|loads| loads.get(hop.direction()))
+                .copied()
+                .unwrap_or_default()),
+        }
+    }
+
+    /// Removes `removed` from and adds `added` to the load on a hop.
+    fn update_hop_load(
+        &mut self,
+        hop: &RoutingHop,
+        removed: LoadT,
+        added: LoadT,
+    ) -> Result<(), ManycoreError> {
+        let core = get_core(&mut self.cores, usize::from(*hop.core_id()))?;
+
+        match hop.routing_type() {
+            RoutingType::OutputChannel => {
+                core.channels_mut()
+                    .remove_from_load(removed, *hop.direction())?;
+                core.channels_mut().add_to_load(added, *hop.direction())
+            }
+            RoutingType::SourceChannel => {
+                if removed != 0 {
+                    core.remove_source_load(removed, hop.direction())?;
+                }
+                core.add_source_load(added, hop.direction())
+            }
+        }
+    }
+
+    /// Re-routes the given task graph edges with the algorithm the system was last routed
+    /// with. Only the old contribution of those edges is subtracted, and the new one added,
+    /// unless the system holds multicast loads, which are recomputed in full.
+    /// Nothing is modified if any of the edges cannot be routed or a load would overflow.
+    fn reroute_edges(&mut self, edge_indices: &BTreeSet<usize>) -> Result<(), ManycoreError> {
+        let algorithm = match self.routing_algorithm.as_ref() {
+            // Observed loads mirror Channels information and are unaffected by edits.
+            None | Some(RoutingAlgorithms::Observed) => return Ok(()),
+            Some(algorithm) => algorithm.clone(),
+        };
+
+        // Edges share tree hops, so an edge's contribution cannot be taken out on its own.
+        if self.routed_multicast {
+            return self.route_multicast(&algorithm).map(|_| ());
+        }
+
+        // Work out every new route before touching any load.
+        let routes = edge_indices
+            .iter()
+            .map(|edge_index| self.edge_route(&algorithm, *edge_index))
+            .collect::<Result<Vec<EdgeRoute>, ManycoreError>>()?;
+
+        // Load removed from and added to each hop.
+        let mut deltas: BTreeMap<RoutingHop, (LoadT, LoadT)> = BTreeMap::new();
+        let overflow = |hop: &RoutingHop| {
+            routing_error(format!(
+                "Load on the {} {:?} of Core with ID {} overflowed.",
+                hop.direction(),
+                hop.routing_type(),
+                hop.core_id()
+            ))
+        };
+        for route in &routes {
+            if let Some(old) = self.edge_routes.route_of(*route.edge_index()) {
+                for hop in old.hops() {
+                    let (removed, _) = deltas.entry(*hop).or_default();
+                    *removed = removed
+                        .checked_add(*old.communication_cost())
+                        .ok_or_else(|| overflow(hop))?;
+                }
+            }
+
+            for hop in route.hops() {
+                let (_, added) = deltas.entry(*hop).or_default();
+                *added = added
+                    .checked_add(*route.communication_cost())
+                    .ok_or_else(|| overflow(hop))?;
+            }
+        }
+
+        // Validate every resulting load before writing any of them.
+        for (hop, (removed, added)) in &deltas {
+            self.hop_load(hop)?
+                .checked_sub(*removed)
+                .ok_or(routing_error(format!(
+                    "Load on the {} {:?} of Core with ID {} underflowed.",
+                    hop.direction(),
+                    hop.routing_type(),
+                    hop.core_id()
+                )))?
+                .checked_add(*added)
+                .ok_or_else(|| overflow(hop))?;
+        }
+
+        // Energy was computed from the loads about to change.
+        self.energy = None;
+
+        // Source ports are fixed by the borders, so an edge never leaves one it was injected
+        // through and no port has to be dropped.
+        for (hop, (removed, added)) in deltas {
+            self.update_hop_load(&hop, removed, added)?;
+        }
+        for route in routes {
+            self.edge_routes.replace(route);
+        }
+
+        Ok(())
+    }
+
+    /// Moves a task onto another core. If the system was routed with a task graph algorithm,
+    /// only the edges entering or leaving the task are re-routed, with results identical to
+    /// a full reroute. Multicast loads are recomputed in full. The system is left untouched if those edges cannot be routed or a
+    /// load would overflow.
+    pub fn reallocate_task(&mut self, task_id: u16, core_id: usize) -> Result<(), ManycoreError> {
+        let previous_core_id = *self
+            .task_core_map
+            .get(&task_id)
+            .ok_or(routing_error(format!(
+                "Task {task_id} is not allocated on any core."
+            )))?;
+
+        if previous_core_id == core_id {
+            return Ok(());
+        }
+
+        let target = get_core(&mut self.cores, core_id)?;
+        if let Some(hosted_task) = target.allocated_task() {
+            return Err(routing_error(format!(
+                "Core with ID {core_id} already hosts task {hosted_task}."
+            )));
+        }
+        target.set_allocated_task(Some(task_id));
+        get_core(&mut self.cores, previous_core_id)?.set_allocated_task(None);
+        self.task_core_map.insert(task_id, core_id);
+
+        let affected: BTreeSet<usize> = self
+            .task_graph
+            .edges()
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| *edge.from() == task_id || *edge.to() == task_id)
+            .map(|(edge_index, _)| edge_index)
+            .collect();
+
+        if let Err(e) = self.reroute_edges(&affected) {
+            // Restore the previous allocation.
+            get_core(&mut self.cores, core_id)?.set_allocated_task(None);
+            get_core(&mut self.cores, previous_core_id)?.set_allocated_task(Some(task_id));
+            self.task_core_map.insert(task_id, previous_core_id);
+
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    /// Changes the communication cost of the task graph edge at `edge_index`. If the system
+    /// was routed with a task graph algorithm, only that edge's loads are updated, with
+    /// results identical to a full reroute. Multicast loads are recomputed in full. The system is left untouched if a load would
+    /// overflow.
+    pub fn set_edge_cost(&mut self, edge_index: usize, cost: LoadT) -> Result<(), ManycoreError> {
+        let edge = self
+            .task_graph
+            .edges_mut()
+            .get_mut(edge_index)
+            .ok_or(routing_error(format!(
+                "Task graph has no edge with index {edge_index}."
+            )))?;
+        let previous_cost = *edge.communication_cost();
+        edge.set_communication_cost(cost);
+
+        if let Err(e) = self.reroute_edges(&BTreeSet::from([edge_index])) {
+            self.task_graph.edges_mut()[edge_index].set_communication_cost(previous_cost);
+
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
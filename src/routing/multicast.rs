@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use getset::Getters;
+
+use crate::{
+    error::ManycoreError, routing_error, ChannelIDT, LoadT, ManycoreSystem, RoutingAlgorithms,
+    RoutingHop, RoutingMap, RoutingResult, RoutingType,
+};
+
+#[cfg(doc)]
+use crate::{Channel, Edge};
+
+/// Multicast routing of a task graph alongside the unicast routing it improves on.
+#[derive(Debug, PartialEq, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct MulticastRouting {
+    /// Loads with every source task's outgoing edges routed as a single tree.
+    multicast: RoutingResult,
+    /// Loads with every [`Edge`] routed and charged on its own.
+    unicast: RoutingResult,
+}
+
+/// Sums every channel and source port load in a [`RoutingResult`].
+fn total_load(result: &RoutingResult) -> Result<LoadT, ManycoreError> {
+    result
+        .channel_loads()
+        .values()
+        .chain(result.source_loads().values())
+        .try_fold(0, |total: LoadT, load| total.checked_add(*load))
+        .ok_or(routing_error("Total routing load overflowed.".into()))
+}
+
+impl MulticastRouting {
+    /// Total load over all channels and source ports when routing as unicast.
+    pub fn unicast_load(&self) -> Result<LoadT, ManycoreError> {
+        total_load(&self.unicast)
+    }
+
+    /// Total load over all channels and source ports when routing as multicast.
+    pub fn multicast_load(&self) -> Result<LoadT, ManycoreError> {
+        total_load(&self.multicast)
+    }
+
+    /// Total load saved by multicast over unicast.
+    pub fn savings(&self) -> Result<LoadT, ManycoreError> {
+        self.unicast_load()?
+            .checked_sub(self.multicast_load()?)
+            .ok_or(routing_error("Multicast load exceeds unicast load.".into()))
+    }
+
+    /// Load saved on each [`Channel`] shared by edges of the same source task.
+    pub fn channel_savings(&self) -> Result<BTreeMap<ChannelIDT, LoadT>, ManycoreError> {
+        let mut savings = BTreeMap::new();
+        for ((core_id, direction), load) in self.unicast.channel_loads() {
+            let saved = load
+                .checked_sub(self.multicast.channel_load(*core_id, *direction))
+                .ok_or(routing_error(format!(
+                    "Multicast load on the {direction} channel of Core with ID {core_id} exceeds its unicast load."
+                )))?;
+
+            if saved > 0 {
+                savings.insert((*core_id, *direction), saved);
+            }
+        }
+
+        Ok(savings)
+    }
+}
+
+impl ManycoreSystem {
+    /// Computes multicast routing under the given algorithm without modifying the system.
+    /// Edges leaving the same task carry the same payload, so they are routed as a tree:
+    /// each channel or source port in the tree is charged once, with the largest load any
+    /// single edge puts on it. As in unicast, an edge whose path crosses a channel more
+    /// than once loads it once per crossing.
+    pub fn compute_multicast_routing(
+        &self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<MulticastRouting, ManycoreError> {
+        if *algorithm == RoutingAlgorithms::Observed {
+            return Err(routing_error(
+                "Observed routing mirrors Channels information and cannot be multicast.".into(),
+            ));
+        }
+
+        let unicast = self.route_task_graph(algorithm)?;
+
+        // Source task -> hop -> cost charged on the hop.
+        let mut trees: BTreeMap<u16, BTreeMap<RoutingHop, LoadT>> = BTreeMap::new();
+        for route in unicast.edge_routes().routes() {
+            let tree = trees.entry(*route.from()).or_default();
+
+            for (hop, load) in route.hop_loads()? {
+                let cost = tree.entry(hop).or_default();
+                *cost = (*cost).max(load);
+            }
+        }
+
+        let mut multicast = RoutingResult::new(algorithm.clone());
+        for (hop, cost) in trees.values().flatten() {
+            let channel = (*hop.core_id(), *hop.direction());
+
+            match hop.routing_type() {
+                RoutingType::OutputChannel => {
+                    multicast.add_channel_load(&self.cores, channel, *cost)?
+                }
+                RoutingType::SourceChannel => {
+                    multicast.add_source_load(&self.cores, channel, *cost)?
+                }
+            }
+        }
+        multicast.set_edge_routes(unicast.edge_routes().clone());
+
+        Ok(MulticastRouting { multicast, unicast })
+    }
+
+    /// Performs multicast routing according to the requested algorithm and stores the
+    /// multicast loads in the system. As edges share tree hops, later edits recompute the
+    /// multicast loads in full rather than incrementally.
+    pub fn route_multicast(
+        &mut self,
+        algorithm: &RoutingAlgorithms,
+    ) -> Result<RoutingMap, ManycoreError> {
+        let routing = self.compute_multicast_routing(algorithm)?;
+        self.apply_routing_result(routing.multicast())?;
+        self.routed_multicast = true;
+
+        Ok(routing.multicast().routing_map().clone())
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::BTreeMap;
+
+use getset::Getters;
+use serde::Serialize;
+
+use crate::{
+    error::ManycoreError, routing_error, utils::loads::serialise_channel_loads, ChannelIDT,
+    Directions, LoadT, ManycoreSystem, RoutingAlgorithms, RoutingHop, RoutingType,
+};
+
+#[cfg(doc)]
+use crate::{Channel, Edge};
+
+/// How an [`Edge`]'s communication cost is split between its minimal paths.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum MultipathSplit {
+    /// Every minimal path carries the same share of the cost.
+    Even,
+    /// Wherever both a row and a column move bring the traffic closer to its destination,
+    /// it is split between them proportionally to these weights. Only the row first path
+    /// is used if `column_first` is zero, and only the column first path if `row_first` is.
+    Weighted { row_first: u32, column_first: u32 },
+}
+
+/// A minimal path carrying part of an [`Edge`]'s traffic.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct PathShare {
+    /// Fraction of the edge's cost carried by the path.
+    share: f64,
+    /// Hops in traversal order, including source and sink border hops.
+    hops: Vec<RoutingHop>,
+}
+
+/// The paths a task graph [`Edge`] is split across.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct EdgeSplit {
+    /// Index of the edge in the task graph.
+    edge_index: usize,
+    /// Edge source task.
+    from: u16,
+    /// Edge destination task.
+    to: u16,
+    /// Edge cost.
+    communication_cost: LoadT,
+    /// The distinct paths used by the edge. A single path carries the whole cost.
+    paths: Vec<PathShare>,
+}
+
+/// The outcome of split-traffic multipath routing.
+#[derive(Serialize, Debug, PartialEq, Clone, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct MultipathRouting {
+    /// The split that was applied.
+    split: MultipathSplit,
+    /// Fractional load on each [`Channel`] that carries any traffic.
+    #[serde(serialize_with = "serialise_channel_loads")]
+    channel_loads: BTreeMap<ChannelIDT, f64>,
+    /// Fractional load on each source port that carries any traffic.
+    #[serde(serialize_with = "serialise_channel_loads")]
+    source_loads: BTreeMap<ChannelIDT, f64>,
+    /// How each edge was split, in task graph edge order.
+    edges: Vec<EdgeSplit>,
+}
+
+/// Utility function to count the minimal paths made of `rows` row moves and `columns`
+/// column moves, i.e. the binomial coefficient of `rows + columns` over `rows`.
+/// Returns [`None`] if the count overflows.
+fn minimal_path_count(rows: usize, columns: usize) -> Option<usize> {
+    let (fewer, total) = (rows.min(columns), rows.checked_add(columns)?);
+
+    // C(n, k) = C(n, k - 1) * (n - k + 1) / k, every intermediate value being a coefficient.
+    (1..=fewer).try_fold(1usize, |count, k| {
+        Some(count.checked_mul(total - fewer + k)? / k)
+    })
+}
+
+/// Extends `path` with every ordering of the pending `rows` and `columns` moves, pushing each
+/// complete path with its share into `paths`. Row moves are tried first, so the row first path
+/// comes first and the column first path last. `row_share` is the fraction of the traffic taking
+/// the row move wherever both moves are possible.
+fn extend_minimal_paths(
+    path: &mut Vec<Directions>,
+    share: f64,
+    rows: (Directions, usize),
+    columns: (Directions, usize),
+    row_share: f64,
+    paths: &mut Vec<(Vec<Directions>, f64)>,
+) {
+    let (row_direction, pending_rows) = rows;
+    let (column_direction, pending_columns) = columns;
+    let both = pending_rows > 0 && pending_columns > 0;
+
+    if pending_rows == 0 && pending_columns == 0 {
+        paths.push((path.clone(), share));
+        return;
+    }
+
+    if pending_rows > 0 {
+        path.push(row_direction);
+        extend_minimal_paths(
+            path,
+            if both { share * row_share } else { share },
+            (row_direction, pending_rows - 1),
+            columns,
+            row_share,
+            paths,
+        );
+        path.pop();
+    }
+
+    if pending_columns > 0 {
+        path.push(column_direction);
+        extend_minimal_paths(
+            path,
+            if both {
+                share * (1.0 - row_share)
+            } else {
+                share
+            },
+            rows,
+            (column_direction, pending_columns - 1),
+            row_share,
+            paths,
+        );
+        path.pop();
+    }
+}
+
+/// Rounds fractional loads to the nearest whole load.
+fn round_loads(loads: &BTreeMap<ChannelIDT, f64>) -> BTreeMap<ChannelIDT, LoadT> {
+    loads
+        .iter()
+        .map(|(channel, load)| (*channel, load.round() as LoadT))
+        .collect()
+}
+
+impl MultipathRouting {
+    /// Load on each [`Channel`], rounded to the nearest whole load.
+    pub fn rounded_channel_loads(&self) -> BTreeMap<ChannelIDT, LoadT> {
+        round_loads(&self.channel_loads)
+    }
+
+    /// Load on each source port, rounded to the nearest whole load.
+    pub fn rounded_source_loads(&self) -> BTreeMap<ChannelIDT, LoadT> {
+        round_loads(&self.source_loads)
+    }
+}
+
+impl ManycoreSystem {
+    /// Routes every task graph edge across all of its minimal paths through the routers
+    /// matrix, splitting its communication cost between them as requested. Edges whose source
+    /// and destination share a row or column keep a single path. Fails if an edge has more
+    /// than `max_paths` minimal paths, as their number grows combinatorially with distance.
+    pub fn compute_multipath_routing(
+        &self,
+        split: MultipathSplit,
+        max_paths: usize,
+    ) -> Result<MultipathRouting, ManycoreError> {
+        let (row_first_weight, column_first_weight) = match split {
+            MultipathSplit::Even => (1.0, 1.0),
+            MultipathSplit::Weighted {
+                row_first,
+                column_first,
+            } => (f64::from(row_first), f64::from(column_first)),
+        };
+        let total_weight = row_first_weight + column_first_weight;
+        if total_weight == 0.0 {
+            return Err(routing_error(
+                "Multipath weights cannot both be zero.".into(),
+            ));
+        }
+
+        let mut channel_loads: BTreeMap<ChannelIDT, f64> = BTreeMap::new();
+        let mut source_loads: BTreeMap<ChannelIDT, f64> = BTreeMap::new();
+        let mut edges = Vec::with_capacity(self.task_graph.edges().len());
+
+        for (edge_index, edge) in self.task_graph.edges().iter().enumerate() {
+            let eri = self.edge_routing_information(edge, &self.task_core_map)?;
+            let rows = (
+                if eri.current_row > eri.destination_row {
+                    Directions::North
+                } else {
+                    Directions::South
+                },
+                usize::from(eri.current_row.abs_diff(eri.destination_row)),
+            );
+            let columns = (
+                if eri.current_column > eri.destination_column {
+                    Directions::West
+                } else {
+                    Directions::East
+                },
+                usize::from(eri.current_column.abs_diff(eri.destination_column)),
+            );
+
+            let path_count = minimal_path_count(rows.1, columns.1)
+                .filter(|count| *count <= max_paths)
+                .ok_or(routing_error(format!(
+                    "Edge {} -> {} has more than {max_paths} minimal paths.",
+                    edge.from(),
+                    edge.to()
+                )))?;
+
+            let mut minimal_paths = Vec::with_capacity(path_count);
+            extend_minimal_paths(
+                &mut Vec::with_capacity(rows.1 + columns.1),
+                1.0,
+                rows,
+                columns,
+                row_first_weight / total_weight,
+                &mut minimal_paths,
+            );
+
+            // Every minimal path shares the row first route's source and sink border hops.
+            let row_route = self.edge_route(&RoutingAlgorithms::RowFirst, edge_index)?;
+            let injected = row_route
+                .hops()
+                .first()
+                .is_some_and(|hop| *hop.routing_type() == RoutingType::SourceChannel);
+            let (source_hops, mesh_hops) = row_route.hops().split_at(usize::from(injected));
+            let sink_hops = &mesh_hops[rows.1 + columns.1..];
+
+            let mut paths = Vec::with_capacity(minimal_paths.len());
+            for (path, share) in &minimal_paths {
+                let mut hops = source_hops.to_vec();
+                for (core_id, direction) in self.path_channels(eri.start_id, path)? {
+                    hops.push(RoutingHop::new(
+                        core_id,
+                        RoutingType::OutputChannel,
+                        direction,
+                    ));
+                }
+                hops.extend_from_slice(sink_hops);
+
+                paths.push(PathShare {
+                    share: match split {
+                        MultipathSplit::Even => 1.0 / minimal_paths.len() as f64,
+                        MultipathSplit::Weighted { .. } => *share,
+                    },
+                    hops,
+                });
+            }
+            paths.retain(|path| path.share > 0.0);
+
+            let cost = *row_route.communication_cost() as f64;
+            for path in &paths {
+                for hop in &path.hops {
+                    let loads = match hop.routing_type() {
+                        RoutingType::OutputChannel => &mut channel_loads,
+                        RoutingType::SourceChannel => &mut source_loads,
+                    };
+
+                    *loads.entry((*hop.core_id(), *hop.direction())).or_default() +=
+                        cost * path.share;
+                }
+            }
+
+            edges.push(EdgeSplit {
+                edge_index: *row_route.edge_index(),
+                from: *row_route.from(),
+                to: *row_route.to(),
+                communication_cost: *row_route.communication_cost(),
+                paths,
+            });
+        }
+
+        Ok(MultipathRouting {
+            split,
+            channel_loads,
+            source_loads,
+            edges,
+        })
+    }
+}
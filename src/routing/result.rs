@@ -0,0 +1,155 @@
+use std::collections::{BTreeMap, HashMap};
+
+use getset::Getters;
+
+use crate::{
+    error::ManycoreError, routing_error, ChannelIDT, Cores, Directions, EdgeRoute, EdgeRoutes,
+    ElementIDT, LoadT, RoutingAlgorithms, RoutingMap, RoutingType, UNSUPPORTED_PLATFORM,
+};
+
+#[cfg(doc)]
+use crate::{Channel, ManycoreSystem};
+
+/// The outcome of routing a [`ManycoreSystem`] with a given algorithm.
+/// It does not borrow from, nor modify, the system it was computed on.
+#[derive(Debug, PartialEq, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct RoutingResult {
+    /// The algorithm that produced this result.
+    algorithm: RoutingAlgorithms,
+    /// Load on each [`Channel`] that carries any traffic.
+    channel_loads: BTreeMap<ChannelIDT, LoadT>,
+    /// Load on each source port that carries any traffic.
+    source_loads: BTreeMap<ChannelIDT, LoadT>,
+    /// Map of the channels and source ports carrying traffic.
+    routing_map: RoutingMap,
+    /// The route followed by each task graph edge.
+    edge_routes: EdgeRoutes,
+}
+
+impl RoutingResult {
+    /// Instantiates an empty [`RoutingResult`] for the given algorithm.
+    pub(crate) fn new(algorithm: RoutingAlgorithms) -> Self {
+        Self {
+            algorithm,
+            channel_loads: BTreeMap::new(),
+            source_loads: BTreeMap::new(),
+            routing_map: HashMap::new(),
+            edge_routes: EdgeRoutes::default(),
+        }
+    }
+
+    /// Utility function to add routing data to the routing map.
+    fn add_to_map(
+        &mut self,
+        core_id: ElementIDT,
+        routing_type: RoutingType,
+        direction: Directions,
+    ) {
+        self.routing_map
+            .entry(core_id)
+            .or_default()
+            .entry(routing_type)
+            .or_default()
+            .insert(direction);
+    }
+
+    /// Adds to the load of a core's output channel.
+    pub(crate) fn add_channel_load(
+        &mut self,
+        cores: &Cores,
+        (core_id, direction): ChannelIDT,
+        cost: LoadT,
+    ) -> Result<(), ManycoreError> {
+        let has_channel = cores
+            .list()
+            .get(usize::try_from(core_id).expect(UNSUPPORTED_PLATFORM))
+            .is_some_and(|core| core.channels().channel().contains_key(&direction));
+
+        if !has_channel {
+            return Err(routing_error(format!(
+                "Core {core_id} is missing {direction} channels."
+            )));
+        }
+
+        let current_load = self.channel_loads.entry((core_id, direction)).or_insert(0);
+        *current_load = current_load.checked_add(cost).ok_or(routing_error(format!(
+            "Load on the {direction} channel of Core with ID {core_id} overflowed."
+        )))?;
+
+        self.add_to_map(core_id, RoutingType::OutputChannel, direction);
+
+        Ok(())
+    }
+
+    /// Adds to the load of a core's source port.
+    pub(crate) fn add_source_load(
+        &mut self,
+        cores: &Cores,
+        (core_id, direction): ChannelIDT,
+        cost: LoadT,
+    ) -> Result<(), ManycoreError> {
+        let on_edge = cores
+            .list()
+            .get(usize::try_from(core_id).expect(UNSUPPORTED_PLATFORM))
+            .is_some_and(|core| core.matrix_edge().is_some());
+
+        if !on_edge {
+            return Err(
+                routing_error(
+                    format!("Malformed TaskGraph: Attempted to add load from a Source on Core with ID {core_id}. The Core is not on the matrix edge.")));
+        }
+
+        let current_load = self.source_loads.entry((core_id, direction)).or_insert(0);
+        *current_load = current_load.checked_add(cost).ok_or(routing_error(format!(
+            "Load on the {direction} source channel of Core with ID {core_id} overflowed."
+        )))?;
+
+        self.add_to_map(core_id, RoutingType::SourceChannel, direction);
+
+        Ok(())
+    }
+
+    /// Adds the cost of a routed task graph edge to every hop it traverses.
+    pub(crate) fn add_route(
+        &mut self,
+        cores: &Cores,
+        route: &EdgeRoute,
+    ) -> Result<(), ManycoreError> {
+        for hop in route.hops() {
+            let channel = (*hop.core_id(), *hop.direction());
+
+            match hop.routing_type() {
+                RoutingType::OutputChannel => {
+                    self.add_channel_load(cores, channel, *route.communication_cost())?
+                }
+                RoutingType::SourceChannel => {
+                    self.add_source_load(cores, channel, *route.communication_cost())?
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stores the per-edge routes.
+    pub(crate) fn set_edge_routes(&mut self, edge_routes: EdgeRoutes) {
+        self.edge_routes = edge_routes;
+    }
+
+    /// Returns the load on a core's output channel. Zero if the channel carries no traffic.
+    pub fn channel_load(&self, core_id: ElementIDT, direction: Directions) -> LoadT {
+        self.channel_loads
+            .get(&(core_id, direction))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the load on a core's source port. Zero if the port carries no traffic.
+    pub fn source_load(&self, core_id: ElementIDT, direction: Directions) -> LoadT {
+        self.source_loads
+            .get(&(core_id, direction))
+            .copied()
+            .unwrap_or_default()
+    }
+}
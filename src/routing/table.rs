@@ -0,0 +1,145 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use serde::Serialize;
+
+use crate::{Directions, ElementIDT, ManycoreSystem, WithID, UNSUPPORTED_PLATFORM};
+
+use super::neighbour;
+
+#[cfg(doc)]
+use crate::RoutingTable;
+
+/// A problem found while following the routers' [`RoutingTable`]s.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+pub enum RoutingTableIssue {
+    /// The router has no entry covering the destination.
+    #[serde(rename_all = "camelCase")]
+    MissingEntry {
+        core_id: ElementIDT,
+        destination: ElementIDT,
+    },
+    /// The router's entry for the destination points outside the matrix.
+    #[serde(rename_all = "camelCase")]
+    LeavesMatrix {
+        core_id: ElementIDT,
+        destination: ElementIDT,
+        direction: Directions,
+    },
+    /// Packets towards the destination cycle through the listed routers, starting from the
+    /// smallest ID.
+    #[serde(rename_all = "camelCase")]
+    Loop {
+        destination: ElementIDT,
+        cores: Vec<ElementIDT>,
+    },
+}
+
+impl Display for RoutingTableIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoutingTableIssue::MissingEntry {
+                core_id,
+                destination,
+            } => write!(
+                f,
+                "Router {core_id} has no routing table entry for destination {destination}."
+            ),
+            RoutingTableIssue::LeavesMatrix {
+                core_id,
+                destination,
+                direction,
+            } => write!(
+                f,
+                "Router {core_id} forwards destination {destination} {direction}, outside the matrix."
+            ),
+            RoutingTableIssue::Loop { destination, cores } => write!(
+                f,
+                "Routing tables loop towards destination {destination} through routers {}.",
+                cores
+                    .iter()
+                    .map(ElementIDT::to_string)
+                    .collect::<Vec<String>>()
+                    .join(" -> ")
+            ),
+        }
+    }
+}
+
+impl ManycoreSystem {
+    /// Follows the routing tables from `start_id` to `destination_id`, returning the
+    /// sequence of output directions or the first problem encountered.
+    pub(crate) fn table_path(
+        &self,
+        start_id: ElementIDT,
+        destination_id: ElementIDT,
+    ) -> Result<Vec<Directions>, RoutingTableIssue> {
+        let mut visited = vec![start_id];
+        let mut path = Vec::new();
+        let mut current_id = start_id;
+
+        while current_id != destination_id {
+            let missing_entry = RoutingTableIssue::MissingEntry {
+                core_id: current_id,
+                destination: destination_id,
+            };
+
+            let direction = self
+                .cores
+                .list()
+                .get(usize::try_from(current_id).expect(UNSUPPORTED_PLATFORM))
+                .and_then(|core| core.router().routing_table().as_ref())
+                .and_then(|table| table.next_hop(destination_id))
+                .ok_or(missing_entry)?;
+
+            current_id = neighbour(
+                current_id,
+                direction,
+                self.columns_in_id_space,
+                self.rows_in_id_space,
+            )
+            .ok_or(RoutingTableIssue::LeavesMatrix {
+                core_id: current_id,
+                destination: destination_id,
+                direction,
+            })?;
+            path.push(direction);
+
+            // Tables only look at the destination, so revisiting a router repeats forever.
+            if let Some(position) = visited.iter().position(|id| *id == current_id) {
+                let mut cores = visited.split_off(position);
+                let smallest = cores
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, id)| **id)
+                    .map_or(0, |(i, _)| i);
+                cores.rotate_left(smallest);
+
+                return Err(RoutingTableIssue::Loop {
+                    destination: destination_id,
+                    cores,
+                });
+            }
+            visited.push(current_id);
+        }
+
+        Ok(path)
+    }
+
+    /// Follows the routing tables between every pair of cores and reports each distinct
+    /// problem found. An empty result means every core can reach every other core.
+    pub fn routing_table_issues(&self) -> Vec<RoutingTableIssue> {
+        let core_ids: Vec<ElementIDT> = self.cores.list().iter().map(|core| *core.id()).collect();
+
+        let mut issues = BTreeSet::new();
+        for destination_id in core_ids.iter().copied() {
+            for start_id in core_ids.iter().copied().filter(|s| *s != destination_id) {
+                if let Err(issue) = self.table_path(start_id, destination_id) {
+                    issues.insert(issue);
+                }
+            }
+        }
+
+        issues.into_iter().collect()
+    }
+}
@@ -1,2 +1,4 @@
+mod analysis;
+mod deadlock;
 mod lib;
-mod routing;
\ No newline at end of file
+mod routing;
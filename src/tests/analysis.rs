@@ -0,0 +1,904 @@
+#[cfg(test)]
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+use crate::{
+    get_core, tests::routing::mesh, Channel, Channels, Directions, Edge, EnergyModel, LatencyModel,
+    LoadT, ManycoreSystem, RoutingAlgorithms, RoutingHop, RoutingResult, RoutingTable,
+    RoutingTableEntry, RoutingType, TaskGraph, TrafficEndpoint, WithID,
+};
+
+#[cfg(test)]
+/// Overwrites every channel's `actualComCost` with the loads in `result`, as if the
+/// system had been observed running it.
+fn observe(manycore: &mut ManycoreSystem, result: &RoutingResult) {
+    for core in manycore.cores_mut().list_mut() {
+        let core_id = *core.id();
+        let channels = core
+            .channels()
+            .channel()
+            .keys()
+            .map(|direction| {
+                (
+                    *direction,
+                    Channel::new(
+                        *direction,
+                        result.channel_load(core_id, *direction),
+                        400,
+                        None,
+                    ),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        core.set_channels(Channels::new(channels));
+    }
+}
+
+#[test]
+fn infers_routing_algorithm() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let column_first = manycore
+        .compute_routing(&RoutingAlgorithms::ColumnFirst)
+        .unwrap();
+    observe(&mut manycore, &column_first);
+
+    let inference = manycore.infer_routing_algorithm(&[0]).unwrap();
+
+    // The file carries no routing tables.
+    assert_eq!(1, inference.skipped().len());
+    assert_eq!(
+        &RoutingAlgorithms::TableBased,
+        inference.skipped()[0].algorithm()
+    );
+    assert_eq!(5, inference.matches().len());
+
+    // The only discrepancy left is the source port, which was recorded as 10 rather than
+    // the 30 edge 0 -> 2 injects.
+    let best = inference.best().unwrap();
+    assert_eq!(&RoutingAlgorithms::ColumnFirst, best.algorithm());
+    assert_eq!(&20, best.total_absolute_residual());
+    assert_eq!(&1, best.mismatches());
+    let mismatch = best.residuals().iter().find(|r| r.residual() != 0).unwrap();
+    assert_eq!(
+        (1, Directions::North, RoutingType::SourceChannel, 10, 30),
+        (
+            *mismatch.core_id(),
+            *mismatch.direction(),
+            *mismatch.routing_type(),
+            *mismatch.observed(),
+            *mismatch.computed()
+        )
+    );
+    // Every channel of the mesh is compared, plus the recorded source port.
+    assert_eq!(9 * 4 + 1, best.residuals().len());
+
+    let row_first = inference
+        .matches()
+        .iter()
+        .find(|m| *m.algorithm() == RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert!(row_first.total_absolute_residual() > best.total_absolute_residual());
+    assert!(inference
+        .matches()
+        .windows(2)
+        .all(|pair| pair[0].total_absolute_residual() <= pair[1].total_absolute_residual()));
+
+    // Loads of a detour around a faulty channel are told apart from RowFirst's.
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    manycore
+        .set_channel_fault(1, Directions::South, true)
+        .unwrap();
+    let fault_tolerant = manycore
+        .compute_routing(&RoutingAlgorithms::FaultTolerant)
+        .unwrap();
+    observe(&mut manycore, &fault_tolerant);
+    // Observing replaced the channels, the fault has to be marked again.
+    manycore
+        .set_channel_fault(1, Directions::South, true)
+        .unwrap();
+
+    let inference = manycore.infer_routing_algorithm(&[0]).unwrap();
+    let best = inference.best().unwrap();
+    assert_eq!(&RoutingAlgorithms::FaultTolerant, best.algorithm());
+    assert_eq!(&20, best.total_absolute_residual());
+    let row_first = inference
+        .matches()
+        .iter()
+        .find(|m| *m.algorithm() == RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert!(row_first.total_absolute_residual() > best.total_absolute_residual());
+
+    // Randomised algorithms are searched over the requested seeds.
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let valiant = manycore
+        .compute_routing(&RoutingAlgorithms::Valiant { seed: 7 })
+        .unwrap();
+    observe(&mut manycore, &valiant);
+
+    let inference = manycore.infer_routing_algorithm(&[0, 7]).unwrap();
+    assert_eq!(7, inference.matches().len());
+    let best = inference.best().unwrap();
+    assert_eq!(&RoutingAlgorithms::Valiant { seed: 7 }, best.algorithm());
+    assert_eq!(&20, best.total_absolute_residual());
+    let other_seed = inference
+        .matches()
+        .iter()
+        .find(|m| *m.algorithm() == RoutingAlgorithms::Valiant { seed: 0 })
+        .unwrap();
+    assert!(other_seed.total_absolute_residual() > best.total_absolute_residual());
+    // Without seeds, no randomised algorithm is considered.
+    let inference = manycore.infer_routing_algorithm(&[]).unwrap();
+    assert_eq!(3, inference.matches().len());
+    assert_ne!(
+        &RoutingAlgorithms::Valiant { seed: 7 },
+        inference.best().unwrap().algorithm()
+    );
+}
+
+#[test]
+fn compares_observed_and_computed_loads() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let observed = manycore
+        .compute_routing(&RoutingAlgorithms::Observed)
+        .unwrap();
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let comparison = manycore.compare_loads(&observed, &row_first).unwrap();
+    let summary = comparison.summary();
+    assert_eq!(&37, summary.compared());
+    assert_eq!(&31, summary.mismatches());
+    assert_eq!(&118, summary.total_observed());
+    assert_eq!(&770, summary.total_computed());
+    assert_eq!(&796, summary.total_absolute_residual());
+    assert_eq!(&176, summary.max_absolute_residual());
+    assert_eq!(796.0 / 37.0, *summary.mean_absolute_residual());
+
+    // Sorted by discrepancy, the busiest row first channel comes out on top.
+    let worst = &comparison.residuals()[0];
+    assert_eq!(
+        (1, Directions::South, 4, 180, 176),
+        (
+            *worst.core_id(),
+            *worst.direction(),
+            *worst.observed(),
+            *worst.computed(),
+            worst.residual()
+        )
+    );
+    assert_eq!(Some(44.0), worst.relative_residual());
+    assert!(comparison
+        .residuals()
+        .windows(2)
+        .all(|pair| pair[0].absolute_residual() >= pair[1].absolute_residual()));
+
+    // Channels without observed load have no relative residual.
+    let unobserved = &comparison.residuals()[2];
+    assert_eq!(
+        (6, Directions::West, 0),
+        (
+            *unobserved.core_id(),
+            *unobserved.direction(),
+            *unobserved.observed()
+        )
+    );
+    assert_eq!(None, unobserved.relative_residual());
+
+    // Comparing a routing with itself yields no discrepancy.
+    let identical = manycore.compare_loads(&row_first, &row_first).unwrap();
+    assert_eq!(&0, identical.summary().mismatches());
+    assert_eq!(&0, identical.summary().max_absolute_residual());
+}
+
+#[test]
+fn reports_channel_utilisation() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let report = manycore.result_utilisation(&row_first);
+
+    // Nothing is routed on the system itself yet.
+    assert_eq!(&0.0, manycore.utilisation().max_utilisation());
+
+    assert_eq!(9 * 4, report.channels().len());
+    assert!(report.over_subscribed().is_empty());
+    // Edges 0 -> 2, 3 -> 4 and 3 -> 5 share core 1's South channel: 180 / 400.
+    assert_eq!(&0.45, report.max_utilisation());
+    assert!((report.average_utilisation() - 740.0 / 400.0 / 36.0).abs() < 1e-12);
+
+    manycore.task_graph_mut().edges_mut()[3].set_communication_cost(1000);
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    let report = manycore.utilisation();
+    assert_eq!(
+        report,
+        manycore.result_utilisation(
+            &manycore
+                .compute_routing(&RoutingAlgorithms::RowFirst)
+                .unwrap()
+        )
+    );
+
+    let over_subscribed = report.over_subscribed();
+    assert!(!over_subscribed.is_empty());
+    for channel in &over_subscribed {
+        assert!(channel.utilisation().unwrap() > 1.0);
+        assert_eq!(
+            *channel.utilisation(),
+            get_core(manycore.cores_mut(), usize::from(*channel.core_id()))
+                .unwrap()
+                .channels()
+                .channel()[channel.direction()]
+            .utilisation()
+        );
+    }
+    assert_eq!(&2.7, report.max_utilisation());
+}
+
+#[test]
+fn estimates_edge_latency() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let report = manycore
+        .estimate_latency(&row_first, &LatencyModel::new(2.0, 4.0))
+        .unwrap();
+    assert_eq!(6, report.edges().len());
+
+    // Task 3 on core 1 to task 4 on core 5: South then East, through routers 1, 4 and 5.
+    let core_to_core = &report.edges()[3];
+    assert_eq!((3, 4), (*core_to_core.from(), *core_to_core.to()));
+    assert_eq!(&2, core_to_core.hop_count());
+    assert_eq!(&3, core_to_core.router_traversals());
+    assert_eq!(&6.0, core_to_core.router_delay());
+    assert_eq!(&(2.0 * 4.0 / 400.0), core_to_core.link_delay());
+    assert_eq!(&(100.0 / 400.0), core_to_core.serialisation_delay());
+
+    // Task 3 to sink task 5 on core 6's West border: the sink link leaves router 6.
+    let into_sink = &report.edges()[4];
+    assert_eq!((3, 5), (*into_sink.from(), *into_sink.to()));
+    assert_eq!(&4, into_sink.hop_count());
+    assert_eq!(&4, into_sink.router_traversals());
+    assert!((into_sink.latency() - (8.0 + 4.0 * 4.0 / 400.0 + 50.0 / 400.0)).abs() < 1e-12);
+
+    let worst = report.worst_case_edge().unwrap();
+    assert_eq!(worst.latency(), report.worst_case_latency());
+    assert!(report
+        .edges()
+        .iter()
+        .all(|edge| edge.latency() <= report.worst_case_latency()));
+    let average = report.edges().iter().map(|e| e.latency()).sum::<f64>() / 6.0;
+    assert!((report.average_latency() - average).abs() < 1e-12);
+}
+
+#[test]
+fn estimates_energy() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let model = EnergyModel::new(1.0, 2.0, 0.5, 0.25, 10.0);
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let report = manycore.compute_energy(&row_first, &model).unwrap();
+
+    // 740 units over channels, 200 more ejected into cores 1, 5 and 7.
+    assert_eq!(&(740.0 * 2.0 + 940.0 * 1.0), report.dynamic_energy());
+    assert_eq!(&((5.0 + 2.5) * 9.0), report.static_energy());
+    assert_eq!(&(1480.0 + 940.0 + 67.5), report.total_energy());
+
+    // Core 1's South channel carries 180, and its router ejects 50 for task 3.
+    assert_eq!(Some(360.0), report.channel_energy(1, Directions::South));
+    let core_1_forwarded = manycore.cores().list()[1]
+        .channels()
+        .channel()
+        .keys()
+        .map(|direction| row_first.channel_load(1, *direction))
+        .sum::<u64>()
+        + 50;
+    assert_eq!(Some(core_1_forwarded as f64 + 5.0), report.router_energy(1));
+    assert_eq!(Some(2.5), report.core_energy(8));
+    assert_eq!(None, report.core_energy(9));
+
+    // Applied energy matches and is dropped once loads change.
+    assert_eq!(&None, manycore.energy());
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    assert_eq!(&report, manycore.apply_energy(&model).unwrap());
+    assert_eq!(&Some(report), manycore.energy());
+    manycore.set_edge_cost(0, 10).unwrap();
+    assert_eq!(&None, manycore.energy());
+
+    // Observed loads carry no ejected traffic to charge routers with.
+    let observed = manycore
+        .compute_routing(&RoutingAlgorithms::Observed)
+        .unwrap();
+    assert_eq!(
+        "Routing Error: Observed loads are not routed from the task graph, their ejected traffic is unknown.",
+        manycore.compute_energy(&observed, &model).unwrap_err().to_string()
+    );
+    manycore.route(&RoutingAlgorithms::Observed).unwrap();
+    assert!(manycore.apply_energy(&model).is_err());
+    assert_eq!(&None, manycore.energy());
+
+    // Traffic ejected into a core is reported rather than saturated when it overflows.
+    manycore
+        .task_graph_mut()
+        .edges_mut()
+        .push(Edge::new(1, 3, LoadT::MAX));
+    assert_eq!(
+        "Routing Error: Load through the router of Core with ID 1 overflowed.",
+        manycore
+            .compute_energy(&row_first, &model)
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn models_channel_contention_as_queues() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let model = LatencyModel::new(2.0, 4.0);
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let report = manycore
+        .estimate_contention_latency(&row_first, &model)
+        .unwrap();
+    assert!(report.saturated().is_empty());
+
+    // Core 1's South channel: utilisation 0.45, a flit served in 4 / 400.
+    let busiest = report
+        .channels()
+        .iter()
+        .find(|c| (*c.core_id(), *c.direction()) == (1, Directions::South))
+        .unwrap();
+    let expected_wait = 0.45 * 0.01 / (2.0 * 0.55);
+    assert!((busiest.waiting_time().unwrap() - expected_wait).abs() < 1e-12);
+
+    // Without contention the queueing model matches the hop-count one.
+    let zero_load = manycore.estimate_latency(&row_first, &model).unwrap();
+    for (edge, zero_load) in report.edges().iter().zip(zero_load.edges()) {
+        assert_eq!(edge.zero_load_latency(), zero_load.latency());
+        assert!(edge.latency().unwrap() > *zero_load.latency());
+    }
+
+    // Edge 3 -> 4 leaves core 1 South, which cannot keep up any more.
+    manycore.task_graph_mut().edges_mut()[3].set_communication_cost(1000);
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let report = manycore
+        .estimate_contention_latency(&row_first, &model)
+        .unwrap();
+    let saturated = report.saturated();
+    assert!(saturated
+        .iter()
+        .any(|c| (*c.core_id(), *c.direction()) == (1, Directions::South)));
+    assert!(saturated.iter().all(|c| c.utilisation().unwrap() >= 1.0));
+    assert_eq!(&None, report.edges()[3].latency());
+}
+
+#[test]
+fn bounds_worst_case_traversal_times() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let model = LatencyModel::new(2.0, 4.0);
+
+    let report = manycore
+        .worst_case_traversal_times(&RoutingAlgorithms::RowFirst, &model)
+        .unwrap();
+    let zero_load = manycore
+        .estimate_latency(
+            &manycore
+                .compute_routing(&RoutingAlgorithms::RowFirst)
+                .unwrap(),
+            &model,
+        )
+        .unwrap()
+        .edges()
+        .iter()
+        .map(|edge| *edge.latency())
+        .collect::<Vec<f64>>();
+
+    // Edge 3 -> 4 shares core 1's South channel with edges 0 -> 2 and 3 -> 5.
+    let bound = &report.edges()[3];
+    assert_eq!(bound.zero_load_latency(), &zero_load[3]);
+    assert_eq!(
+        vec![
+            (0, vec![(1, Directions::South)]),
+            (4, vec![(1, Directions::South)])
+        ],
+        bound
+            .interference()
+            .iter()
+            .map(|i| (*i.edge_index(), i.shared_channels().clone()))
+            .collect::<Vec<_>>()
+    );
+    // Once past core 1, edge 3 -> 5 can be held up by edge 4 -> 5 into the sink, which
+    // edge 3 -> 4 never meets.
+    assert_eq!(&vec![5], bound.indirect_interference());
+
+    // Edge 0 -> 2 goes on to share core 4's South channel with edge 3 -> 5, which then
+    // waits for edge 4 -> 5 before releasing it, and so on down to the sink.
+    let edge_4_holding = zero_load[4] + (zero_load[5] + zero_load[4]);
+    let edge_0_holding = zero_load[0] + edge_4_holding;
+    // Edge 3 -> 5 is held up by edge 0 -> 2 on core 4 and by edge 4 -> 5 on core 7.
+    let edge_4_first_holding = zero_load[4] + zero_load[0] + (zero_load[5] + zero_load[4]);
+    assert!((bound.interference()[0].delay() - edge_0_holding).abs() < 1e-9);
+    assert!((bound.interference()[1].delay() - edge_4_first_holding).abs() < 1e-9);
+    assert!((bound.bound() - (zero_load[3] + edge_0_holding + edge_4_first_holding)).abs() < 1e-9);
+
+    // Edge 1 -> 2 shares no channel and keeps its zero load latency.
+    let bound = &report.edges()[1];
+    assert!(bound.interference().is_empty());
+    assert!(bound.indirect_interference().is_empty());
+    assert_eq!(bound.bound(), &zero_load[1]);
+
+    for edge in report.edges() {
+        assert!(edge.bound() >= edge.zero_load_latency());
+        assert!(!edge.indirect_interference().contains(edge.edge_index()));
+    }
+
+    assert_eq!(
+        "Routing Error: Worst case traversal time analysis requires dimension ordered routing, Valiant { seed: 0 } is not supported.",
+        manycore
+            .worst_case_traversal_times(&RoutingAlgorithms::Valiant { seed: 0 }, &model)
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn summarises_load_balance() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let balance = manycore.load_balance(&row_first, 3).unwrap();
+
+    assert_eq!(&36, balance.channels());
+    assert_eq!(&12, balance.channels_used());
+    assert_eq!(&180, balance.max_load());
+    assert_eq!(&(740.0 / 36.0), balance.mean_load());
+    assert!(balance.load_std_dev() > &0.0);
+    assert_eq!(
+        vec![
+            (1, Directions::South, 180),
+            (4, Directions::East, 100),
+            (4, Directions::South, 80)
+        ],
+        balance
+            .hottest()
+            .iter()
+            .map(|c| (*c.core_id(), *c.direction(), *c.load()))
+            .collect::<Vec<_>>()
+    );
+
+    // Every edge's cost counted once per channel it traverses.
+    let hop_weighted = row_first
+        .edge_routes()
+        .routes()
+        .iter()
+        .map(|route| {
+            route.communication_cost()
+                * route
+                    .hops()
+                    .iter()
+                    .filter(|hop| *hop.routing_type() == RoutingType::OutputChannel)
+                    .count() as u64
+        })
+        .sum::<u64>();
+    assert_eq!(&hop_weighted, balance.hop_weighted_traffic());
+
+    let json = serde_json::to_value(&balance).unwrap();
+    assert_eq!(740, json["hopWeightedTraffic"]);
+    assert_eq!("South", json["hottest"][0]["direction"]);
+
+    // A single loaded channel out of 8 is as uneven as it gets.
+    let mut pair = mesh(2, 1);
+    pair.task_core_map_mut().extend([(0, 0), (1, 1)]);
+    pair.set_task_graph(TaskGraph::new(BTreeMap::new(), vec![Edge::new(0, 1, 10)]));
+    let balance = pair
+        .load_balance(
+            &pair.compute_routing(&RoutingAlgorithms::RowFirst).unwrap(),
+            10,
+        )
+        .unwrap();
+    assert_eq!(&(7.0 / 8.0), balance.gini());
+    assert_eq!(8, balance.hottest().len());
+
+    // Without traffic there is nothing to be uneven about.
+    let idle = mesh(2, 1);
+    let balance = idle
+        .load_balance(
+            &idle.compute_routing(&RoutingAlgorithms::RowFirst).unwrap(),
+            1,
+        )
+        .unwrap();
+    assert_eq!((&0.0, &0), (balance.gini(), balance.channels_used()));
+
+    // Channels that each fit their load can still overflow the hop weighted total.
+    pair.set_task_graph(TaskGraph::new(
+        BTreeMap::new(),
+        vec![
+            Edge::new(0, 1, LoadT::MAX / 2 + 1),
+            Edge::new(1, 0, LoadT::MAX / 2 + 1),
+        ],
+    ));
+    assert_eq!(
+        "Routing Error: Hop weighted traffic overflowed.",
+        pair.load_balance(
+            &pair.compute_routing(&RoutingAlgorithms::RowFirst).unwrap(),
+            1
+        )
+        .unwrap_err()
+        .to_string()
+    );
+}
+
+#[test]
+fn compares_routing_algorithms() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    manycore.route(&RoutingAlgorithms::ColumnFirst).unwrap();
+    let before = manycore.cores().clone();
+
+    let model = LatencyModel::new(2.0, 4.0);
+    let comparison = manycore.compare_all_algorithms(&model, &[0]).unwrap();
+
+    // The routed state is left alone.
+    assert_eq!(&before, manycore.cores());
+    assert_eq!(
+        &Some(RoutingAlgorithms::ColumnFirst),
+        manycore.routing_algorithm()
+    );
+
+    assert_eq!(36, comparison.channels().len());
+    assert_eq!(6, comparison.algorithms().len());
+    assert_eq!(
+        &RoutingAlgorithms::TableBased,
+        comparison.skipped()[0].algorithm()
+    );
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    for metrics in comparison.algorithms() {
+        assert_eq!(comparison.channels().len(), metrics.loads().len());
+        assert!(metrics.load_balance().hottest().is_empty());
+    }
+
+    let metrics = &comparison.algorithms()[1];
+    assert_eq!(&RoutingAlgorithms::RowFirst, metrics.algorithm());
+    assert_eq!(
+        &manycore.load_balance(&row_first, 0).unwrap(),
+        metrics.load_balance()
+    );
+    assert_eq!(
+        &Some(
+            *manycore
+                .estimate_latency(&row_first, &model)
+                .unwrap()
+                .worst_case_latency()
+        ),
+        metrics.worst_case_latency()
+    );
+    assert_eq!(&0.45, metrics.max_utilisation());
+    let (core_id, direction) = comparison.channels()[6];
+    assert_eq!(
+        row_first.channel_load(core_id, direction),
+        metrics.loads()[6]
+    );
+
+    // Observed loads are not routed edge by edge.
+    assert_eq!(
+        &RoutingAlgorithms::Observed,
+        comparison.algorithms()[0].algorithm()
+    );
+    assert_eq!(&None, comparison.algorithms()[0].average_latency());
+
+    let subset = manycore
+        .compare_algorithms(&[RoutingAlgorithms::ColumnFirst], &model)
+        .unwrap();
+    assert_eq!(1, subset.algorithms().len());
+    assert_eq!(&comparison.algorithms()[2], &subset.algorithms()[0]);
+
+    // Randomised algorithms are evaluated once per requested seed.
+    let seeded = manycore.compare_all_algorithms(&model, &[3, 7]).unwrap();
+    assert_eq!(
+        vec![
+            RoutingAlgorithms::Observed,
+            RoutingAlgorithms::RowFirst,
+            RoutingAlgorithms::ColumnFirst,
+            RoutingAlgorithms::FaultTolerant,
+            RoutingAlgorithms::Valiant { seed: 3 },
+            RoutingAlgorithms::Valiant { seed: 7 },
+            RoutingAlgorithms::O1Turn { seed: 3 },
+            RoutingAlgorithms::O1Turn { seed: 7 },
+        ],
+        seeded
+            .algorithms()
+            .iter()
+            .map(|metrics| metrics.algorithm().clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        4,
+        manycore
+            .compare_all_algorithms(&model, &[])
+            .unwrap()
+            .algorithms()
+            .len()
+    );
+
+    // Algorithms whose metrics cannot be worked out are skipped like unroutable ones.
+    let mut pair = mesh(2, 1);
+    pair.task_core_map_mut().extend([(0, 0), (1, 1)]);
+    pair.set_task_graph(TaskGraph::new(
+        BTreeMap::new(),
+        vec![
+            Edge::new(0, 1, LoadT::MAX / 2 + 1),
+            Edge::new(1, 0, LoadT::MAX / 2 + 1),
+        ],
+    ));
+    let overflowing = pair
+        .compare_algorithms(&[RoutingAlgorithms::RowFirst], &model)
+        .unwrap();
+    assert!(overflowing.algorithms().is_empty());
+    assert_eq!(
+        "Routing Error: Hop weighted traffic overflowed.",
+        overflowing.skipped()[0].reason()
+    );
+}
+
+#[test]
+fn attributes_bottlenecks_to_edges() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let hottest = RoutingHop::new(1, RoutingType::OutputChannel, Directions::South);
+    let attribution = manycore.attribute_load(&row_first, &hottest).unwrap();
+
+    assert_eq!(&180, attribution.load());
+    assert_eq!(
+        vec![(3, 4, 100), (3, 5, 50), (0, 2, 30)],
+        attribution
+            .contributions()
+            .iter()
+            .map(|c| (*c.from(), *c.to(), *c.load()))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(&(100.0 / 180.0), attribution.contributions()[0].share());
+
+    // Source 0 injects edge 0 -> 2 only.
+    let source = RoutingHop::new(1, RoutingType::SourceChannel, Directions::North);
+    let attribution = manycore.attribute_load(&row_first, &source).unwrap();
+    assert_eq!(&30, attribution.load());
+    assert_eq!(&1.0, attribution.contributions()[0].share());
+
+    assert_eq!(
+        "Routing Error: Observed loads are not routed edge by edge and cannot be attributed.",
+        manycore
+            .attribute_load(
+                &manycore
+                    .compute_routing(&RoutingAlgorithms::Observed)
+                    .unwrap(),
+                &hottest
+            )
+            .unwrap_err()
+            .to_string()
+    );
+
+    let task_core_map = manycore.task_core_map().clone();
+    let suggestion = manycore
+        .suggest_reallocation(&RoutingAlgorithms::RowFirst)
+        .unwrap()
+        .expect("Some single move lowers the peak.");
+    assert_eq!(&task_core_map, manycore.task_core_map());
+    assert_eq!(&180, suggestion.peak_before());
+    assert!(suggestion.peak_after() < suggestion.peak_before());
+
+    // Performing the move yields the predicted peak.
+    manycore
+        .reallocate_task(*suggestion.task_id(), *suggestion.to_core())
+        .unwrap();
+    let moved = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(
+        Some(suggestion.peak_after()),
+        moved.channel_loads().values().max()
+    );
+}
+
+#[test]
+fn builds_distance_and_traffic_matrices() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let distances = manycore
+        .hop_distance_matrix(&RoutingAlgorithms::ColumnFirst)
+        .unwrap();
+    assert_eq!(9, distances.distances().len());
+    for start_id in 0..9u16 {
+        for destination_id in 0..9u16 {
+            let manhattan = (start_id / 3).abs_diff(destination_id / 3)
+                + (start_id % 3).abs_diff(destination_id % 3);
+            assert_eq!(
+                Some(usize::from(manhattan)),
+                distances.distance(start_id, destination_id)
+            );
+        }
+    }
+    assert_eq!(None, distances.distance(9, 0));
+    assert!(distances
+        .to_csv()
+        .starts_with(",0,1,2,3,4,5,6,7,8\n0,0,1,2,1,2,3,2,3,4\n"));
+    assert!(manycore
+        .hop_distance_matrix(&RoutingAlgorithms::Observed)
+        .is_err());
+
+    let traffic = manycore.traffic_matrix().unwrap();
+    assert_eq!(
+        &vec![
+            TrafficEndpoint::Core(0),
+            TrafficEndpoint::Core(1),
+            TrafficEndpoint::Core(2),
+            TrafficEndpoint::Core(3),
+            TrafficEndpoint::Core(4),
+            TrafficEndpoint::Core(5),
+            TrafficEndpoint::Core(6),
+            TrafficEndpoint::Core(7),
+            TrafficEndpoint::Core(8),
+            TrafficEndpoint::Source(0),
+            TrafficEndpoint::Source(1),
+            TrafficEndpoint::Sink(5),
+        ],
+        traffic.endpoints()
+    );
+    let between = |from, to| traffic.traffic_between(&from, &to).unwrap();
+    assert_eq!(
+        30,
+        between(TrafficEndpoint::Source(0), TrafficEndpoint::Core(7))
+    );
+    assert_eq!(
+        20,
+        between(TrafficEndpoint::Source(1), TrafficEndpoint::Core(7))
+    );
+    assert_eq!(
+        50,
+        between(TrafficEndpoint::Core(7), TrafficEndpoint::Core(1))
+    );
+    assert_eq!(
+        100,
+        between(TrafficEndpoint::Core(1), TrafficEndpoint::Core(5))
+    );
+    assert_eq!(
+        50,
+        between(TrafficEndpoint::Core(1), TrafficEndpoint::Sink(5))
+    );
+    assert_eq!(
+        30,
+        between(TrafficEndpoint::Core(5), TrafficEndpoint::Sink(5))
+    );
+    assert_eq!(
+        0,
+        between(TrafficEndpoint::Core(5), TrafficEndpoint::Core(1))
+    );
+    assert_eq!(280, traffic.traffic().iter().flatten().sum::<u64>());
+
+    let csv = traffic.to_csv();
+    assert!(csv.starts_with(",0,1,2,3,4,5,6,7,8,source 0,source 1,sink 5\n"));
+    assert!(csv.contains("\nsource 0,0,0,0,0,0,0,0,30,0,0,0,0\n"));
+}
+
+#[test]
+fn partial_routing_tables_leave_distances_empty() {
+    let mut manycore = ManycoreSystem::parse_file("tests/RoutingTables.xml")
+        .expect("Could not read input test file \"tests/RoutingTables.xml\"");
+
+    // Router 8 knows nothing about destinations 6 and 7.
+    get_core(manycore.cores_mut(), 8)
+        .unwrap()
+        .router_mut()
+        .set_routing_table(Some(RoutingTable::new(vec![RoutingTableEntry::new(
+            0,
+            Some(5),
+            Directions::North,
+        )])));
+
+    let distances = manycore
+        .hop_distance_matrix(&RoutingAlgorithms::TableBased)
+        .unwrap();
+    assert_eq!(Some(4), distances.distance(8, 0));
+    assert_eq!(None, distances.distance(8, 6));
+    assert_eq!(None, distances.distance(8, 7));
+    // Router 5 reaches destinations 6 and 7 through router 8, so it cannot reach them either.
+    assert_eq!(None, distances.distance(5, 6));
+    assert_eq!(None, distances.distance(5, 7));
+    assert_eq!(Some(2), distances.distance(5, 3));
+    assert!(distances.to_csv().contains("\n8,4,3,2,3,2,1,,,0\n"));
+}
+
+#[test]
+fn accounts_router_input_loads() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let routers = manycore.result_router_loads(&row_first).unwrap();
+    assert_eq!(9, routers.len());
+
+    // Router 1 takes source 0 from the North, task 2's traffic back from router 4, and
+    // task 3's own traffic from its core.
+    let router = &routers[1];
+    assert_eq!(
+        &BTreeMap::from([(Directions::North, 30), (Directions::South, 50)]),
+        router.inputs()
+    );
+    assert_eq!(
+        (&150, &50, &230),
+        (router.injection(), router.ejection(), router.throughput())
+    );
+
+    // Router 4 only forwards.
+    let router = &routers[4];
+    assert_eq!(180, router.input_load(&Directions::North));
+    assert_eq!(0, router.input_load(&Directions::West));
+    assert_eq!(
+        (&0, &0, &230),
+        (router.injection(), router.ejection(), router.throughput())
+    );
+
+    // Whatever enters a router leaves it, through a channel or to its core.
+    for router in &routers {
+        let outputs = manycore.cores().list()[usize::from(*router.router_id())]
+            .channels()
+            .channel()
+            .keys()
+            .map(|direction| row_first.channel_load(*router.router_id(), *direction))
+            .sum::<u64>();
+        assert_eq!(*router.throughput(), outputs + router.ejection());
+    }
+
+    // Before routing, nothing travels between routers.
+    assert!(manycore
+        .router_loads()
+        .unwrap()
+        .iter()
+        .all(|router| router.inputs().is_empty()));
+    manycore.apply_routing_result(&row_first).unwrap();
+    assert_eq!(routers, manycore.router_loads().unwrap());
+
+    // Observed loads carry no task traffic to attribute to local ports.
+    let observed = manycore
+        .compute_routing(&RoutingAlgorithms::Observed)
+        .unwrap();
+    assert_eq!(
+        "Routing Error: Observed loads are not routed from the task graph, their injected and ejected traffic is unknown.",
+        manycore.result_router_loads(&observed).unwrap_err().to_string()
+    );
+    manycore.apply_routing_result(&observed).unwrap();
+    assert!(manycore.router_loads().is_err());
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+use crate::{
+    get_core, ChannelDependencyGraph, Directions, ManycoreSystem, RoutingAlgorithms, RoutingTable,
+    RoutingTableEntry,
+};
+
+#[test]
+fn dimension_ordered_routing_is_deadlock_free() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    for algorithm in [RoutingAlgorithms::RowFirst, RoutingAlgorithms::ColumnFirst] {
+        let mesh = manycore.mesh_channel_dependencies(&algorithm).unwrap();
+        assert!(mesh.is_deadlock_free());
+        // Every channel between two cores is used by some pair: 12 horizontal, 12 vertical.
+        assert_eq!(24, mesh.dependencies().len());
+
+        let task_graph = manycore
+            .task_graph_channel_dependencies(&algorithm)
+            .unwrap();
+        assert!(task_graph.cycles().is_empty());
+    }
+}
+
+#[test]
+fn row_first_task_graph_dependencies() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let graph = manycore
+        .task_graph_channel_dependencies(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+
+    // Edge 3 -> 5 goes 1 -> 4 -> 7 -> 6, then into the sink.
+    let dependencies = graph.dependencies();
+    assert!(dependencies[&(1, Directions::South)].contains(&(4, Directions::South)));
+    assert!(dependencies[&(4, Directions::South)].contains(&(7, Directions::West)));
+    assert!(dependencies[&(7, Directions::West)].contains(&(6, Directions::West)));
+    assert!(dependencies[&(6, Directions::West)].is_empty());
+
+    // A routing result yields the same graph.
+    let result = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(graph, ChannelDependencyGraph::from(result.edge_routes()));
+}
+
+#[test]
+fn cycles_are_reported() {
+    // Four routes turning clockwise around the 2x2 block of cores 0, 1, 4, 3.
+    let routes = vec![
+        vec![(0, Directions::East), (1, Directions::South)],
+        vec![(1, Directions::South), (4, Directions::West)],
+        vec![(4, Directions::West), (3, Directions::North)],
+        vec![(3, Directions::North), (0, Directions::East)],
+        vec![(5, Directions::West), (4, Directions::West)],
+    ];
+
+    let graph = ChannelDependencyGraph::from_routes(&routes);
+
+    assert!(!graph.is_deadlock_free());
+    assert_eq!(
+        vec![vec![
+            (0, Directions::East),
+            (1, Directions::South),
+            (4, Directions::West),
+            (3, Directions::North),
+        ]],
+        graph.cycles()
+    );
+}
+
+#[test]
+fn observed_cannot_build_dependency_graph() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    assert!(manycore
+        .mesh_channel_dependencies(&RoutingAlgorithms::Observed)
+        .is_err());
+}
+
+#[test]
+fn partial_routing_tables_contribute_no_dependencies() {
+    let mut manycore = ManycoreSystem::parse_file("tests/RoutingTables.xml")
+        .expect("Could not read input test file \"tests/RoutingTables.xml\"");
+
+    // Router 8 knows nothing about destinations 6 and 7.
+    get_core(manycore.cores_mut(), 8)
+        .unwrap()
+        .router_mut()
+        .set_routing_table(Some(RoutingTable::new(vec![RoutingTableEntry::new(
+            0,
+            Some(5),
+            Directions::North,
+        )])));
+
+    let mesh = manycore
+        .mesh_channel_dependencies(&RoutingAlgorithms::TableBased)
+        .unwrap();
+    // Only router 8 would continue West into router 7, for destination 6.
+    assert!(!mesh.dependencies().contains_key(&(8, Directions::West)));
+    assert!(mesh.dependencies()[&(8, Directions::North)].contains(&(5, Directions::North)));
+}
@@ -7,9 +7,10 @@ use std::{
 #[cfg(test)]
 use crate::{
     AttributeType, AttributesMap, BorderEntry, Borders, Channel, Channels, ConfigurableAttributes,
-    Core, Cores, Directions, Edge, ElementIDT, ManycoreSystem, ProcessedAttribute, Router, Sink,
-    SinkSourceDirection, Source, Task, TaskGraph, WithID, BORDER_ROUTERS_KEY, COORDINATES_KEY,
-    ID_KEY, ROUTING_KEY, SUPPORTED_ALGORITHMS, TASK_COST_KEY,
+    Core, Cores, Directions, Edge, EdgeRoutes, ElementIDT, ManycoreSystem, ProcessedAttribute,
+    Router, RoutingAlgorithms, Sink, SinkSourceDirection, Source, Task, TaskGraph, WithID,
+    BORDER_ROUTERS_KEY, COORDINATES_KEY, ENERGY_KEY, ID_KEY, INPUT_LOADS_KEY, ROUTING_KEY,
+    TASK_COST_KEY, THROUGHPUT_KEY, UTILISATION_KEY,
 };
 
 #[cfg(test)]
@@ -250,8 +251,9 @@ fn can_parse() {
     expected_core_conf_attrs.insert_manual(ID_KEY, AttributeType::Text);
     expected_core_conf_attrs.insert_manual(COORDINATES_KEY, AttributeType::Coordinates);
     expected_core_conf_attrs.insert_manual(TASK_COST_KEY, AttributeType::Boolean);
+    expected_core_conf_attrs.insert_manual(ENERGY_KEY, AttributeType::Energy);
 
-    let expected_router_conf_attrs = BTreeMap::from([
+    let mut expected_router_conf_attrs = BTreeMap::from([
         (
             age_string.clone(),
             ProcessedAttribute::new(&age_string, AttributeType::Number),
@@ -265,6 +267,9 @@ fn can_parse() {
             ProcessedAttribute::new(&status_string, AttributeType::Text),
         ),
     ]);
+    expected_router_conf_attrs.insert_manual(ENERGY_KEY, AttributeType::Energy);
+    expected_router_conf_attrs.insert_manual(INPUT_LOADS_KEY, AttributeType::InputLoads);
+    expected_router_conf_attrs.insert_manual(THROUGHPUT_KEY, AttributeType::Throughput);
 
     let mut expected_channel_conf_attrs = BTreeMap::from([
         (
@@ -277,13 +282,19 @@ fn can_parse() {
         ),
     ]);
     expected_channel_conf_attrs.insert_manual(ROUTING_KEY, AttributeType::Routing);
+    expected_channel_conf_attrs.insert_manual(UTILISATION_KEY, AttributeType::Utilisation);
+    expected_channel_conf_attrs.insert_manual(ENERGY_KEY, AttributeType::Energy);
     expected_channel_conf_attrs.insert_manual(BORDER_ROUTERS_KEY, AttributeType::Boolean);
 
     let expected_configurable_attributes = ConfigurableAttributes::new(
         expected_core_conf_attrs,
         expected_router_conf_attrs,
         Some(String::from("RowFirst")),
-        Vec::from(&SUPPORTED_ALGORITHMS),
+        vec![
+            RoutingAlgorithms::Observed,
+            RoutingAlgorithms::RowFirst,
+            RoutingAlgorithms::ColumnFirst,
+        ],
         expected_channel_conf_attrs,
     );
 
@@ -324,6 +335,10 @@ fn can_parse() {
         cores: Cores::new(expected_cores),
         task_graph: expected_graph,
         task_core_map: expected_task_core_map,
+        edge_routes: EdgeRoutes::default(),
+        routing_algorithm: None,
+        routed_multicast: false,
+        energy: None,
         configurable_attributes: expected_configurable_attributes
     };
 
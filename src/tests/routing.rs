@@ -1,14 +1,71 @@
+#[cfg(test)]
+use std::collections::{BTreeMap, HashMap};
+
 #[cfg(test)]
 use crate::{
-    get_core, routing_error, Directions, ManycoreError, ManycoreSystem, RoutingAlgorithms,
+    get_core, routing_error, Channel, Channels, ConfigurableAttributes, Core, Cores, Directions,
+    Edge, EdgeRoute, EdgeRoutes, ElementIDT, LoadT, ManycoreError, ManycoreSystem, MultipathSplit,
+    RouteChoice, Router, RoutingAlgorithms, RoutingEvent, RoutingHop, RoutingSnapshot,
+    RoutingTable, RoutingTableEntry, RoutingTableIssue, RoutingType, SystemDimensionsT, TaskGraph,
+    WrappingSystemDimensionsT,
 };
 
+#[cfg(test)]
+/// Builds a mesh of the given size with every channel in place and an empty task graph.
+pub(crate) fn mesh(columns: SystemDimensionsT, rows: SystemDimensionsT) -> ManycoreSystem {
+    let number_of_cores = ElementIDT::from(columns) * ElementIDT::from(rows);
+
+    let cores = (0..number_of_cores)
+        .map(|id| {
+            let channels = Channels::new(BTreeMap::from_iter(
+                [
+                    Directions::North,
+                    Directions::South,
+                    Directions::East,
+                    Directions::West,
+                ]
+                .map(|direction| (direction, Channel::new(direction, 0, 400, None))),
+            ));
+
+            Core::new(
+                id,
+                columns,
+                rows,
+                Router::new(id, None),
+                None,
+                channels,
+                None,
+            )
+        })
+        .collect();
+
+    ManycoreSystem {
+        xmlns: String::new(),
+        xmlns_si: String::new(),
+        xsi_schema_location: String::new(),
+        rows,
+        rows_in_id_space: ElementIDT::from(rows),
+        columns,
+        columns_in_id_space: ElementIDT::from(columns),
+        routing_algo: None,
+        task_graph: TaskGraph::new(BTreeMap::new(), vec![]),
+        cores: Cores::new(cores),
+        borders: None,
+        task_core_map: HashMap::new(),
+        edge_routes: EdgeRoutes::default(),
+        routing_algorithm: None,
+        routed_multicast: false,
+        energy: None,
+        configurable_attributes: ConfigurableAttributes::default(),
+    }
+}
+
 #[cfg(test)]
 fn get_load(
     manycore: &mut ManycoreSystem,
     core_id: usize,
     direction: Directions,
-) -> Result<u16, ManycoreError> {
+) -> Result<LoadT, ManycoreError> {
     Ok(*get_core(manycore.cores_mut(), core_id)?
         .channels()
         .channel()
@@ -24,7 +81,7 @@ fn get_source_load(
     manycore: &mut ManycoreSystem,
     id: usize,
     direction: Directions,
-) -> Result<u16, ManycoreError> {
+) -> Result<LoadT, ManycoreError> {
     Ok(*get_core(manycore.cores_mut(), id)?
         .source_loads()
         .as_ref()
@@ -130,3 +187,1081 @@ fn observed_is_correct() {
         get_source_load(&mut manycore, 1, Directions::North).unwrap()
     );
 }
+
+#[test]
+fn fault_tolerant_matches_row_first_without_faults() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let row_first = manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    let row_first_cores = manycore.cores().clone();
+
+    let fault_tolerant = manycore.route(&RoutingAlgorithms::FaultTolerant).unwrap();
+
+    assert_eq!(row_first, fault_tolerant);
+    assert_eq!(&row_first_cores, manycore.cores());
+}
+
+#[test]
+fn fault_tolerant_detours_around_faults() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Edge 3 -> 4 goes 1 -> 4 -> 5 under RowFirst. Block it.
+    manycore
+        .set_channel_fault(1, Directions::South, true)
+        .unwrap();
+    // Nothing may cross the centre router either.
+    manycore.set_router_fault(4, true).unwrap();
+
+    manycore.route(&RoutingAlgorithms::FaultTolerant).unwrap();
+
+    assert_eq!(0, get_load(&mut manycore, 1, Directions::South).unwrap());
+    assert_eq!(100, get_load(&mut manycore, 1, Directions::East).unwrap());
+    assert_eq!(100, get_load(&mut manycore, 2, Directions::South).unwrap());
+    for direction in [
+        Directions::North,
+        Directions::South,
+        Directions::West,
+        Directions::East,
+    ] {
+        assert_eq!(0, get_load(&mut manycore, 4, direction).unwrap());
+    }
+    assert_eq!(
+        30,
+        get_source_load(&mut manycore, 1, Directions::North).unwrap()
+    );
+}
+
+#[test]
+fn fault_tolerant_reports_unreachable_edge() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Every router and channel in the fixture has status="Normal".
+    assert_eq!(45, manycore.mark_faults_by_attribute("status", "Normal"));
+
+    let error = manycore
+        .route(&RoutingAlgorithms::FaultTolerant)
+        .unwrap_err();
+    assert!(error.to_string().contains("Edge 0 -> 2 is unreachable"));
+
+    manycore.clear_faults();
+    assert!(manycore.route(&RoutingAlgorithms::FaultTolerant).is_ok());
+
+    // Keys as they appear in the attributes map are accepted too.
+    assert_eq!(45, manycore.mark_faults_by_attribute("@status", "Normal"));
+    assert_eq!(0, manycore.mark_faults_by_attribute("status", "Faulty"));
+}
+
+#[test]
+fn edge_routes_are_recorded() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    let load = get_load(&mut manycore, 4, Directions::South).unwrap();
+
+    let edge_routes = manycore.edge_routes();
+    assert_eq!(6, edge_routes.routes().len());
+
+    // Edge 0 -> 2 enters from the North source on core 1 and ends on core 7.
+    let source_route = edge_routes.route_of(0).unwrap();
+    assert_eq!(
+        &vec![
+            RoutingHop::new(1, RoutingType::SourceChannel, Directions::North),
+            RoutingHop::new(1, RoutingType::OutputChannel, Directions::South),
+            RoutingHop::new(4, RoutingType::OutputChannel, Directions::South),
+        ],
+        source_route.hops()
+    );
+
+    // Edge 3 -> 5 ends in the West sink on core 6.
+    let sink_route = edge_routes.route_of(4).unwrap();
+    assert_eq!(
+        (3, 5, 50),
+        (
+            *sink_route.from(),
+            *sink_route.to(),
+            *sink_route.communication_cost()
+        )
+    );
+    assert_eq!(
+        &vec![
+            RoutingHop::new(1, RoutingType::OutputChannel, Directions::South),
+            RoutingHop::new(4, RoutingType::OutputChannel, Directions::South),
+            RoutingHop::new(7, RoutingType::OutputChannel, Directions::West),
+            RoutingHop::new(6, RoutingType::OutputChannel, Directions::West),
+        ],
+        sink_route.hops()
+    );
+
+    // Reverse index agrees with channel loads.
+    let traversing = edge_routes.traversing(&RoutingHop::new(
+        4,
+        RoutingType::OutputChannel,
+        Directions::South,
+    ));
+    assert_eq!(
+        vec![0, 4],
+        traversing
+            .iter()
+            .map(|route| *route.edge_index())
+            .collect::<Vec<usize>>()
+    );
+    assert_eq!(
+        load,
+        traversing
+            .iter()
+            .map(|route| *route.communication_cost())
+            .sum::<LoadT>()
+    );
+
+    // Observed routing does not follow paths.
+    manycore.route(&RoutingAlgorithms::Observed).unwrap();
+    assert!(manycore.edge_routes().routes().is_empty());
+}
+
+#[test]
+fn compute_routing_leaves_system_untouched() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    let pristine = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Evaluate every algorithm side by side, from several threads.
+    let results = std::thread::scope(|scope| {
+        let handles = [
+            RoutingAlgorithms::Observed,
+            RoutingAlgorithms::RowFirst,
+            RoutingAlgorithms::ColumnFirst,
+        ]
+        .map(|algorithm| {
+            let manycore = &manycore;
+            scope.spawn(move || manycore.compute_routing(&algorithm).unwrap())
+        });
+
+        handles.map(|handle| handle.join().unwrap())
+    });
+
+    assert_eq!(pristine, manycore);
+
+    let row_first = &results[1];
+    assert_eq!(&RoutingAlgorithms::RowFirst, row_first.algorithm());
+    assert_eq!(180, row_first.channel_load(1, Directions::South));
+    assert_eq!(30, row_first.source_load(1, Directions::North));
+    assert_eq!(0, row_first.channel_load(2, Directions::South));
+
+    let column_first = &results[2];
+    assert_eq!(100, column_first.channel_load(1, Directions::East));
+    assert_eq!(50, column_first.channel_load(1, Directions::South));
+
+    // Applying a result matches routing in place.
+    let map = manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    assert_eq!(&map, row_first.routing_map());
+    assert_eq!(manycore.edge_routes(), row_first.edge_routes());
+    for (core_id, direction) in row_first.channel_loads().keys() {
+        assert_eq!(
+            row_first.channel_load(*core_id, *direction),
+            get_load(&mut manycore, usize::from(*core_id), *direction).unwrap()
+        );
+    }
+
+    manycore.apply_routing_result(&results[0]).unwrap();
+    assert_eq!(
+        10,
+        get_source_load(&mut manycore, 1, Directions::North).unwrap()
+    );
+    assert!(manycore.edge_routes().routes().is_empty());
+}
+
+#[test]
+fn loads_are_wide_and_overflow_checked() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Costs wider than 16 bits accumulate correctly.
+    manycore
+        .task_graph_mut()
+        .edges_mut()
+        .push(Edge::new(3, 4, 100_000));
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    assert_eq!(
+        100_100,
+        get_load(&mut manycore, 4, Directions::East).unwrap()
+    );
+
+    // Overflowing a channel is reported rather than wrapped, and the stored routing is kept.
+    manycore
+        .task_graph_mut()
+        .edges_mut()
+        .push(Edge::new(3, 4, LoadT::MAX));
+    let error = manycore.route(&RoutingAlgorithms::RowFirst).unwrap_err();
+    assert!(error.to_string().contains("overflowed"));
+    assert_eq!(
+        100_100,
+        get_load(&mut manycore, 4, Directions::East).unwrap()
+    );
+
+    // Source channels are checked too.
+    manycore.task_graph_mut().edges_mut().pop();
+    manycore
+        .task_graph_mut()
+        .edges_mut()
+        .push(Edge::new(1, 2, LoadT::MAX));
+    assert!(manycore.route(&RoutingAlgorithms::RowFirst).is_err());
+}
+
+#[test]
+fn dimension_ordered_routing_on_rectangular_meshes() {
+    let mut sizes: Vec<(SystemDimensionsT, SystemDimensionsT)> = (1..=6)
+        .flat_map(|columns| (1..=6).map(move |rows| (columns, rows)))
+        .collect();
+    sizes.extend([(2, 9), (9, 2), (3, 8), (8, 3), (1, 12), (12, 1)]);
+
+    for (columns, rows) in sizes {
+        let manycore = mesh(columns, rows);
+        let columns = WrappingSystemDimensionsT::from(columns);
+        let rows = WrappingSystemDimensionsT::from(rows);
+        let number_of_cores = ElementIDT::try_from(columns * rows).unwrap();
+
+        for algorithm in [RoutingAlgorithms::RowFirst, RoutingAlgorithms::ColumnFirst] {
+            for start_id in 0..number_of_cores {
+                for destination_id in 0..number_of_cores {
+                    let path = manycore
+                        .core_to_core_channel_path(&algorithm, start_id, destination_id)
+                        .unwrap()
+                        .expect("Dimension ordered routing must reach every core.");
+
+                    let start = WrappingSystemDimensionsT::from(start_id);
+                    let destination = WrappingSystemDimensionsT::from(destination_id);
+                    let (mut row, mut column) = (start / columns, start % columns);
+                    let distance = (destination / columns - row).abs()
+                        + (destination % columns - column).abs();
+                    assert_eq!(
+                        usize::try_from(distance).unwrap(),
+                        path.len(),
+                        "{algorithm:?} path {start_id} -> {destination_id} on a {columns}x{rows} mesh is not minimal."
+                    );
+
+                    let mut second_dimension_reached = false;
+                    for (core_id, direction) in path {
+                        assert_eq!(
+                            WrappingSystemDimensionsT::from(core_id),
+                            row * columns + column
+                        );
+
+                        match direction {
+                            Directions::North => row -= 1,
+                            Directions::South => row += 1,
+                            Directions::West => column -= 1,
+                            Directions::East => column += 1,
+                        }
+                        assert!((0..rows).contains(&row) && (0..columns).contains(&column));
+
+                        // Rows are travelled North/South, columns East/West.
+                        let vertical = matches!(direction, Directions::North | Directions::South);
+                        let first_dimension =
+                            vertical == (algorithm == RoutingAlgorithms::RowFirst);
+                        assert!(
+                            !(first_dimension && second_dimension_reached),
+                            "{algorithm:?} path {start_id} -> {destination_id} on a {columns}x{rows} mesh is not dimension ordered."
+                        );
+                        second_dimension_reached |= !first_dimension;
+                    }
+
+                    assert_eq!(row * columns + column, destination);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn routes_non_square_mesh() {
+    // 4 columns, 2 rows. Task 0 on core 7 (row 1, column 3), task 1 on core 1 (row 0, column 1).
+    let mut manycore = mesh(4, 2);
+    manycore.task_core_map = HashMap::from([(0, 7), (1, 1)]);
+    manycore.task_graph = TaskGraph::new(BTreeMap::new(), vec![Edge::new(0, 1, 10)]);
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(
+        &BTreeMap::from([
+            ((7, Directions::North), 10),
+            ((3, Directions::West), 10),
+            ((2, Directions::West), 10),
+        ]),
+        row_first.channel_loads()
+    );
+
+    let column_first = manycore
+        .compute_routing(&RoutingAlgorithms::ColumnFirst)
+        .unwrap();
+    assert_eq!(
+        &BTreeMap::from([
+            ((7, Directions::West), 10),
+            ((6, Directions::West), 10),
+            ((5, Directions::North), 10),
+        ]),
+        column_first.channel_loads()
+    );
+}
+
+#[test]
+fn table_based_follows_routing_tables() {
+    let manycore = ManycoreSystem::parse_file("tests/RoutingTables.xml")
+        .expect("Could not read input test file \"tests/RoutingTables.xml\"");
+
+    assert_eq!(
+        &Some(RoutingTable::new(vec![
+            RoutingTableEntry::new(3, Some(8), Directions::South),
+            RoutingTableEntry::new(1, Some(2), Directions::East),
+        ])),
+        manycore.cores().list()[0].router().routing_table()
+    );
+    assert!(manycore.routing_table_issues().is_empty());
+
+    // The tables in the file encode row first routing.
+    let table_based = manycore
+        .compute_routing(&RoutingAlgorithms::TableBased)
+        .unwrap();
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(row_first.channel_loads(), table_based.channel_loads());
+    assert_eq!(row_first.source_loads(), table_based.source_loads());
+}
+
+#[test]
+fn routing_table_issues_are_reported() {
+    let mut manycore = ManycoreSystem::parse_file("tests/RoutingTables.xml")
+        .expect("Could not read input test file \"tests/RoutingTables.xml\"");
+
+    let mut set_table = |core_id: usize, entries: Vec<RoutingTableEntry>| {
+        get_core(manycore.cores_mut(), core_id)
+            .unwrap()
+            .router_mut()
+            .set_routing_table(Some(RoutingTable::new(entries)));
+    };
+    // Router 4 sends destination 0 back North to router 1, which sends it South again.
+    set_table(
+        1,
+        vec![
+            RoutingTableEntry::new(0, None, Directions::South),
+            RoutingTableEntry::new(2, None, Directions::East),
+            RoutingTableEntry::new(3, Some(8), Directions::South),
+        ],
+    );
+    // Router 2 forwards destination 0 off the matrix.
+    set_table(
+        2,
+        vec![
+            RoutingTableEntry::new(0, None, Directions::North),
+            RoutingTableEntry::new(1, None, Directions::West),
+            RoutingTableEntry::new(3, Some(8), Directions::South),
+        ],
+    );
+    // Router 8 knows nothing about destinations 6 and 7.
+    set_table(
+        8,
+        vec![RoutingTableEntry::new(0, Some(5), Directions::North)],
+    );
+
+    assert_eq!(
+        vec![
+            RoutingTableIssue::MissingEntry {
+                core_id: 8,
+                destination: 6
+            },
+            RoutingTableIssue::MissingEntry {
+                core_id: 8,
+                destination: 7
+            },
+            RoutingTableIssue::LeavesMatrix {
+                core_id: 2,
+                destination: 0,
+                direction: Directions::North
+            },
+            RoutingTableIssue::Loop {
+                destination: 0,
+                cores: vec![1, 4]
+            },
+        ],
+        manycore.routing_table_issues()
+    );
+
+    // Move task 4 onto core 0 so that edge 3 -> 4 runs into the loop.
+    manycore.task_core_map_mut().insert(4, 0);
+    let err = manycore
+        .compute_routing(&RoutingAlgorithms::TableBased)
+        .unwrap_err();
+    assert_eq!(
+        "Routing Error: Routing tables loop towards destination 0 through routers 1 -> 4.",
+        err.to_string()
+    );
+}
+
+#[test]
+fn routing_tables_are_exported() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let column_first = manycore
+        .routing_tables(&RoutingAlgorithms::ColumnFirst)
+        .unwrap();
+    assert_eq!(Some(Directions::East), column_first.next_hop(0, 8));
+    assert_eq!(Some(Directions::North), column_first.next_hop(7, 1));
+    assert_eq!(None, column_first.next_hop(4, 4));
+    assert_eq!(
+        Some(RoutingTable::new(vec![
+            RoutingTableEntry::new(0, None, Directions::West),
+            RoutingTableEntry::new(1, None, Directions::North),
+            RoutingTableEntry::new(2, None, Directions::East),
+            RoutingTableEntry::new(3, None, Directions::West),
+            RoutingTableEntry::new(5, None, Directions::East),
+            RoutingTableEntry::new(6, None, Directions::West),
+            RoutingTableEntry::new(7, None, Directions::South),
+            RoutingTableEntry::new(8, None, Directions::East),
+        ])),
+        column_first.routing_table(4)
+    );
+
+    assert_eq!(
+        Some(RoutingTable::new(vec![
+            RoutingTableEntry::new(1, Some(2), Directions::East),
+            RoutingTableEntry::new(3, Some(8), Directions::South),
+        ])),
+        manycore
+            .routing_tables(&RoutingAlgorithms::RowFirst)
+            .unwrap()
+            .routing_table(0)
+    );
+
+    let csv = column_first.to_csv();
+    assert_eq!(1 + 9 * 8, csv.lines().count());
+    assert!(csv.starts_with("router,destination,direction\n0,1,East\n"));
+
+    // Loading the exported tables into the routers reproduces the algorithm.
+    for router_id in 0..9 {
+        let table = column_first.routing_table(router_id);
+        get_core(manycore.cores_mut(), usize::from(router_id))
+            .unwrap()
+            .router_mut()
+            .set_routing_table(table);
+    }
+    assert!(manycore.routing_table_issues().is_empty());
+    assert_eq!(
+        manycore
+            .compute_routing(&RoutingAlgorithms::ColumnFirst)
+            .unwrap()
+            .channel_loads(),
+        manycore
+            .compute_routing(&RoutingAlgorithms::TableBased)
+            .unwrap()
+            .channel_loads()
+    );
+
+    let xml = mesh(2, 1)
+        .routing_tables(&RoutingAlgorithms::RowFirst)
+        .unwrap()
+        .to_xml()
+        .unwrap();
+    assert_eq!(
+        "<RoutingTables algorithm=\"RowFirst\">
+    <Router id=\"0\">
+        <RoutingTable>
+            <Entry from=\"1\" direction=\"East\"/>
+        </RoutingTable>
+    </Router>
+    <Router id=\"1\">
+        <RoutingTable>
+            <Entry from=\"0\" direction=\"West\"/>
+        </RoutingTable>
+    </Router>
+</RoutingTables>",
+        xml
+    );
+}
+
+#[test]
+fn partial_routing_tables_are_exported() {
+    let mut manycore = ManycoreSystem::parse_file("tests/RoutingTables.xml")
+        .expect("Could not read input test file \"tests/RoutingTables.xml\"");
+
+    // Router 8 knows nothing about destinations 6 and 7.
+    get_core(manycore.cores_mut(), 8)
+        .unwrap()
+        .router_mut()
+        .set_routing_table(Some(RoutingTable::new(vec![RoutingTableEntry::new(
+            0,
+            Some(5),
+            Directions::North,
+        )])));
+
+    let tables = manycore
+        .routing_tables(&RoutingAlgorithms::TableBased)
+        .unwrap();
+    assert_eq!(Some(Directions::North), tables.next_hop(8, 0));
+    assert_eq!(None, tables.next_hop(8, 6));
+    assert_eq!(None, tables.next_hop(8, 7));
+    // Router 5 reaches destination 6 through router 8, so it cannot reach it either.
+    assert_eq!(None, tables.next_hop(5, 6));
+    assert_eq!(Some(Directions::South), tables.next_hop(5, 8));
+    assert_eq!(
+        Some(RoutingTable::new(vec![RoutingTableEntry::new(
+            0,
+            Some(5),
+            Directions::North
+        )])),
+        tables.routing_table(8)
+    );
+
+    // Tables leading off the matrix are still reported.
+    get_core(manycore.cores_mut(), 2)
+        .unwrap()
+        .router_mut()
+        .set_routing_table(Some(RoutingTable::new(vec![RoutingTableEntry::new(
+            0,
+            None,
+            Directions::North,
+        )])));
+    assert_eq!(
+        "Routing Error: Router 2 forwards destination 0 North, outside the matrix.",
+        manycore
+            .routing_tables(&RoutingAlgorithms::TableBased)
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn randomised_routing_is_seeded() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let column_first = manycore
+        .compute_routing(&RoutingAlgorithms::ColumnFirst)
+        .unwrap();
+
+    let mut choices = Vec::new();
+    for seed in 0..8 {
+        let o1turn = RoutingAlgorithms::O1Turn { seed };
+        let result = manycore.compute_routing(&o1turn).unwrap();
+        assert_eq!(manycore.compute_routing(&o1turn).unwrap(), result);
+
+        // Each edge follows exactly the dimension order it was assigned.
+        for route in result.edge_routes().routes() {
+            let expected = match route.choice() {
+                Some(RouteChoice::RowFirst) => &row_first,
+                Some(RouteChoice::ColumnFirst) => &column_first,
+                choice => panic!("Unexpected O1TURN choice {choice:?}"),
+            };
+            assert_eq!(
+                expected
+                    .edge_routes()
+                    .route_of(*route.edge_index())
+                    .unwrap()
+                    .hops(),
+                route.hops()
+            );
+            choices.push(*route.choice());
+        }
+
+        let valiant = RoutingAlgorithms::Valiant { seed };
+        let result = manycore.compute_routing(&valiant).unwrap();
+        assert_eq!(manycore.compute_routing(&valiant).unwrap(), result);
+
+        // Each edge goes through its intermediate core, or ends there.
+        for route in result.edge_routes().routes() {
+            let Some(RouteChoice::Intermediate(intermediate)) = *route.choice() else {
+                panic!("Unexpected Valiant choice {:?}", route.choice());
+            };
+            let ends_on_intermediate = manycore
+                .task_core_map()
+                .get(route.to())
+                .is_some_and(|core_id| *core_id == usize::from(intermediate));
+
+            assert!(
+                ends_on_intermediate
+                    || route
+                        .hops()
+                        .iter()
+                        .any(|hop| *hop.core_id() == intermediate)
+            );
+            choices.push(*route.choice());
+        }
+    }
+
+    // Seeds must actually change the decisions taken.
+    choices.sort_by_key(|choice| format!("{choice:?}"));
+    choices.dedup();
+    assert!(choices.len() > 3);
+
+    assert!(manycore
+        .routing_tables(&RoutingAlgorithms::Valiant { seed: 0 })
+        .is_err());
+}
+
+#[test]
+fn routing_algorithms_are_told_apart() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // A non-zero seed is as deterministic as the default one, and takes other paths.
+    for (seeded, default) in [
+        (
+            RoutingAlgorithms::Valiant { seed: 7 },
+            RoutingAlgorithms::Valiant { seed: 0 },
+        ),
+        (
+            RoutingAlgorithms::O1Turn { seed: 7 },
+            RoutingAlgorithms::O1Turn { seed: 0 },
+        ),
+    ] {
+        let result = manycore.compute_routing(&seeded).unwrap();
+        assert_eq!(manycore.compute_routing(&seeded).unwrap(), result);
+        assert_ne!(
+            manycore.compute_routing(&default).unwrap().edge_routes(),
+            result.edge_routes(),
+            "{seeded:?}"
+        );
+    }
+
+    // With core 1's South channel down, FaultTolerant detours where RowFirst does not.
+    manycore
+        .set_channel_fault(1, Directions::South, true)
+        .unwrap();
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let fault_tolerant = manycore
+        .compute_routing(&RoutingAlgorithms::FaultTolerant)
+        .unwrap();
+    assert_eq!(180, row_first.channel_load(1, Directions::South));
+    assert_eq!(0, fault_tolerant.channel_load(1, Directions::South));
+    assert_ne!(row_first.channel_loads(), fault_tolerant.channel_loads());
+}
+
+#[test]
+fn multicast_charges_shared_channels_once() {
+    let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let routing = manycore
+        .compute_multicast_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(
+        &manycore
+            .compute_routing(&RoutingAlgorithms::RowFirst)
+            .unwrap(),
+        routing.unicast()
+    );
+
+    // Task 3 (core 1) feeds task 4 (core 5, cost 100) and the sink on core 6 (cost 50).
+    // Both leave core 1 through its South channel, so the cheaper edge rides along for free.
+    assert_eq!(180, routing.unicast().channel_load(1, Directions::South));
+    assert_eq!(130, routing.multicast().channel_load(1, Directions::South));
+    // Core 4's South channel carries edges from different source tasks and is unaffected.
+    assert_eq!(80, routing.multicast().channel_load(4, Directions::South));
+    assert_eq!(
+        BTreeMap::from([((1, Directions::South), 50)]),
+        routing.channel_savings().unwrap()
+    );
+    assert_eq!(50, routing.savings().unwrap());
+    assert_eq!(
+        routing.unicast_load().unwrap() - 50,
+        routing.multicast_load().unwrap()
+    );
+
+    manycore
+        .route_multicast(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(130, get_load(&mut manycore, 1, Directions::South).unwrap());
+    assert!(manycore.routed_multicast());
+
+    // Edits recompute the multicast loads, matching a fresh multicast run.
+    let mut reference = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+    manycore.reallocate_task(4, 2).unwrap();
+    manycore.set_edge_cost(4, 120).unwrap();
+    reference.reallocate_task(4, 2).unwrap();
+    reference.set_edge_cost(4, 120).unwrap();
+    reference
+        .route_multicast(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    assert_eq!(reference.cores(), manycore.cores());
+    assert_eq!(reference.edge_routes(), manycore.edge_routes());
+    assert_eq!(
+        &Some(RoutingAlgorithms::RowFirst),
+        manycore.routing_algorithm()
+    );
+    assert!(manycore.routed_multicast());
+
+    // Unicast routing drops the multicast loads.
+    manycore.route(&RoutingAlgorithms::RowFirst).unwrap();
+    assert!(!manycore.routed_multicast());
+
+    assert!(manycore
+        .compute_multicast_routing(&RoutingAlgorithms::Observed)
+        .is_err());
+}
+
+#[test]
+fn multicast_charges_each_crossing_of_a_channel() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // An edge crossing core 4's East channel twice loads it twice, as unicast does.
+    let east = RoutingHop::new(4, RoutingType::OutputChannel, Directions::East);
+    let west = RoutingHop::new(5, RoutingType::OutputChannel, Directions::West);
+    let route = EdgeRoute::new(3, 3, 4, 50, vec![east, west, east], None);
+    assert_eq!(
+        BTreeMap::from([(east, 100), (west, 50)]),
+        route.hop_loads().unwrap()
+    );
+
+    // A tree hop carries at least the load of every edge crossing it, and replaying the
+    // run reproduces the tree loads.
+    let routing = manycore
+        .compute_multicast_routing(&RoutingAlgorithms::Valiant { seed: 7 })
+        .unwrap();
+    for route in routing.unicast().edge_routes().routes() {
+        for (hop, load) in route.hop_loads().unwrap() {
+            let charged = match hop.routing_type() {
+                RoutingType::OutputChannel => routing
+                    .multicast()
+                    .channel_load(*hop.core_id(), *hop.direction()),
+                RoutingType::SourceChannel => routing
+                    .multicast()
+                    .source_load(*hop.core_id(), *hop.direction()),
+            };
+            assert!(charged >= load, "{hop:?}");
+        }
+    }
+    let events = manycore.multicast_routing_events(&routing).unwrap();
+    assert_eq!(
+        routing.multicast().channel_loads(),
+        events.snapshot(events.len()).channel_loads()
+    );
+}
+
+#[test]
+fn multipath_splits_edges_across_minimal_paths() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    // Weighting a single dimension order reproduces it.
+    for (split, algorithm) in [
+        (
+            MultipathSplit::Weighted {
+                row_first: 1,
+                column_first: 0,
+            },
+            RoutingAlgorithms::RowFirst,
+        ),
+        (
+            MultipathSplit::Weighted {
+                row_first: 0,
+                column_first: 1,
+            },
+            RoutingAlgorithms::ColumnFirst,
+        ),
+    ] {
+        let multipath = manycore.compute_multipath_routing(split, 6).unwrap();
+        let result = manycore.compute_routing(&algorithm).unwrap();
+
+        assert_eq!(result.channel_loads(), &multipath.rounded_channel_loads());
+        for (split, route) in multipath.edges().iter().zip(result.edge_routes().routes()) {
+            assert_eq!(1, split.paths().len());
+            assert_eq!(route.hops(), split.paths()[0].hops());
+        }
+    }
+
+    let row_first = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let column_first = manycore
+        .compute_routing(&RoutingAlgorithms::ColumnFirst)
+        .unwrap();
+    let even = manycore
+        .compute_multipath_routing(MultipathSplit::Even, 6)
+        .unwrap();
+    // Edge 0 -> 2 runs from core 1 to core 7, down a single column.
+    assert_eq!(1, even.edges()[0].paths().len());
+    // Edge 3 -> 5 runs from core 1 to the sink on core 6, along three minimal paths from
+    // row first to column first, each followed by the sink hop.
+    let paths = even.edges()[4].paths();
+    assert_eq!(
+        vec![1.0 / 3.0; 3],
+        paths.iter().map(|path| *path.share()).collect::<Vec<_>>()
+    );
+    assert_eq!(row_first.edge_routes().routes()[4].hops(), paths[0].hops());
+    assert_eq!(
+        &vec![
+            RoutingHop::new(1, RoutingType::OutputChannel, Directions::South),
+            RoutingHop::new(4, RoutingType::OutputChannel, Directions::West),
+            RoutingHop::new(3, RoutingType::OutputChannel, Directions::South),
+            RoutingHop::new(6, RoutingType::OutputChannel, Directions::West),
+        ],
+        paths[1].hops()
+    );
+    assert_eq!(
+        column_first.edge_routes().routes()[4].hops(),
+        paths[2].hops()
+    );
+    // 30 from edge 0 -> 2, a third of 20 from 1 -> 2, half of 100 from 3 -> 4 and two
+    // thirds of 50 from 3 -> 5.
+    assert_eq!(
+        Some(&120),
+        even.rounded_channel_loads().get(&(1, Directions::South))
+    );
+    assert_eq!(row_first.source_loads(), &even.rounded_source_loads());
+
+    // Weighted splits are taken wherever both dimensions make progress.
+    let weighted = manycore
+        .compute_multipath_routing(
+            MultipathSplit::Weighted {
+                row_first: 3,
+                column_first: 1,
+            },
+            6,
+        )
+        .unwrap();
+    assert_eq!(
+        vec![0.75 * 0.75, 0.75 * 0.25, 0.25],
+        weighted.edges()[4]
+            .paths()
+            .iter()
+            .map(|path| *path.share())
+            .collect::<Vec<_>>()
+    );
+    for split in [&even, &weighted] {
+        for edge in split.edges() {
+            let total: f64 = edge.paths().iter().map(|path| path.share()).sum();
+            assert!((1.0 - total).abs() < 1e-9);
+        }
+    }
+
+    // Fractional loads serialise as one entry per channel.
+    let json = serde_json::to_value(&even).unwrap();
+    assert_eq!(
+        serde_json::json!([
+            { "coreId": 0, "direction": "West", "load": 20.0 },
+            { "coreId": 1, "direction": "North", "load": 30.0 }
+        ]),
+        json["sourceLoads"]
+    );
+    assert!(json["channelLoads"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!({ "coreId": 4, "direction": "North", "load": 50.0 })));
+
+    assert!(manycore
+        .compute_multipath_routing(
+            MultipathSplit::Weighted {
+                row_first: 0,
+                column_first: 0
+            },
+            6
+        )
+        .is_err());
+    // Edge 1 -> 2 runs from core 0 to core 7, along three minimal paths.
+    assert_eq!(
+        "Routing Error: Edge 1 -> 2 has more than 2 minimal paths.",
+        manycore
+            .compute_multipath_routing(MultipathSplit::Even, 2)
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn routing_events_replay_a_run() {
+    let manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+        .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+
+    let result = manycore
+        .compute_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let events = manycore.routing_events(&result).unwrap();
+
+    // Edge 0 -> 2 enters from the source on core 1 and travels down to core 7.
+    assert_eq!(
+        vec![
+            RoutingEvent::EdgeStarted {
+                edge_index: 0,
+                from: 0,
+                to: 2,
+                communication_cost: 30
+            },
+            RoutingEvent::SourceInjection {
+                edge_index: 0,
+                core_id: 1,
+                direction: Directions::North,
+                load: 30
+            },
+            RoutingEvent::Hop {
+                edge_index: 0,
+                core_id: 1,
+                direction: Directions::South,
+                load: 30
+            },
+            RoutingEvent::Hop {
+                edge_index: 0,
+                core_id: 4,
+                direction: Directions::South,
+                load: 30
+            },
+            RoutingEvent::EdgeFinished { edge_index: 0 },
+        ],
+        events.iter().take(5).copied().collect::<Vec<_>>()
+    );
+
+    // Edge 4 -> 5 is the last one and ends into the sink on core 6.
+    assert_eq!(
+        Some(&RoutingEvent::SinkEjection {
+            edge_index: 5,
+            core_id: 6,
+            direction: Directions::West,
+            load: 80
+        }),
+        events.get(events.len() - 2)
+    );
+
+    // Replaying yields the same run, and scrubbing to the end yields the final loads.
+    assert!(events.iter().eq(&events));
+    assert_eq!(&RoutingSnapshot::default(), &events.snapshot(0));
+    let snapshot = events.snapshot(events.len());
+    assert_eq!(result.channel_loads(), snapshot.channel_loads());
+    assert_eq!(result.source_loads(), snapshot.source_loads());
+    assert_eq!(
+        Some(&30),
+        events
+            .snapshot(3)
+            .channel_loads()
+            .get(&(1, Directions::South))
+    );
+    assert_eq!(
+        serde_json::json!({
+            "channelLoads": [{ "coreId": 1, "direction": "South", "load": 30 }],
+            "sourceLoads": [{ "coreId": 1, "direction": "North", "load": 30 }]
+        }),
+        serde_json::to_value(events.snapshot(3)).unwrap()
+    );
+
+    // Multicast runs replay their tree loads, not the unicast ones.
+    let routing = manycore
+        .compute_multicast_routing(&RoutingAlgorithms::RowFirst)
+        .unwrap();
+    let events = manycore.multicast_routing_events(&routing).unwrap();
+    let snapshot = events.snapshot(events.len());
+    assert_eq!(
+        routing.multicast().channel_loads(),
+        snapshot.channel_loads()
+    );
+    assert_eq!(routing.multicast().source_loads(), snapshot.source_loads());
+    assert_eq!(
+        "Routing Error: Replayed loads do not match the result's, was it computed on this system?",
+        manycore
+            .routing_events(routing.multicast())
+            .unwrap_err()
+            .to_string()
+    );
+    assert!(manycore
+        .routing_events(
+            &manycore
+                .compute_routing(&RoutingAlgorithms::Observed)
+                .unwrap()
+        )
+        .is_err());
+}
+
+#[test]
+fn incremental_rerouting_matches_full_reroute() {
+    let parse = || {
+        ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+            .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"")
+    };
+
+    for algorithm in [
+        RoutingAlgorithms::RowFirst,
+        RoutingAlgorithms::ColumnFirst,
+        RoutingAlgorithms::FaultTolerant,
+        RoutingAlgorithms::Valiant { seed: 3 },
+        RoutingAlgorithms::O1Turn { seed: 5 },
+    ] {
+        let mut incremental = parse();
+        incremental.route(&algorithm).unwrap();
+
+        // The reference is edited first, then routed from scratch.
+        let mut reference = parse();
+
+        incremental.reallocate_task(4, 2).unwrap();
+        reference.reallocate_task(4, 2).unwrap();
+        incremental.set_edge_cost(3, 7).unwrap();
+        reference.set_edge_cost(3, 7).unwrap();
+        // Task 3 moves next to the source on core 0.
+        incremental.reallocate_task(3, 0).unwrap();
+        reference.reallocate_task(3, 0).unwrap();
+
+        reference.route(&algorithm).unwrap();
+        assert_eq!(reference, incremental, "{algorithm:?}");
+    }
+
+    let mut manycore = parse();
+    manycore.set_router_fault(8, true).unwrap();
+    manycore.route(&RoutingAlgorithms::FaultTolerant).unwrap();
+    let mut reference = parse();
+    reference.set_router_fault(8, true).unwrap();
+    reference.route(&RoutingAlgorithms::FaultTolerant).unwrap();
+
+    // Failed edits leave the system untouched.
+    assert!(manycore.reallocate_task(4, 8).is_err());
+    assert!(manycore.reallocate_task(4, 7).is_err());
+    assert!(manycore.set_edge_cost(42, 1).is_err());
+    assert_eq!(reference, manycore);
+    assert_eq!(Some(4), *manycore.cores().list()[5].allocated_task());
+}
+
+#[test]
+fn overflowing_edits_leave_system_untouched() {
+    let parse = |algorithm: &RoutingAlgorithms| {
+        let mut manycore = ManycoreSystem::parse_file("tests/VisualiserOutput1.xml")
+            .expect("Could not read input test file \"tests/VisualiserOutput1.xml\"");
+        manycore.route(algorithm).unwrap();
+        manycore
+    };
+
+    let mut manycore = parse(&RoutingAlgorithms::RowFirst);
+    let reference = parse(&RoutingAlgorithms::RowFirst);
+
+    // Edge 0 -> 2 is injected through core 1's North source channel, which fits the new
+    // cost, then overflows core 1's South channel shared with other edges.
+    let error = manycore.set_edge_cost(0, LoadT::MAX - 100).unwrap_err();
+    assert_eq!(
+        "Routing Error: Load on the South OutputChannel of Core with ID 1 overflowed.",
+        error.to_string()
+    );
+    assert_eq!(reference, manycore);
+    assert_eq!(30, *manycore.task_graph().edges()[0].communication_cost());
+
+    // Column first, core 1's South channel only carries edge 0 -> 2 until task 4 moves onto
+    // core 4 and edge 3 -> 4 is routed down it too.
+    let mut manycore = parse(&RoutingAlgorithms::ColumnFirst);
+    let mut reference = parse(&RoutingAlgorithms::ColumnFirst);
+    manycore.set_edge_cost(0, LoadT::MAX - 50).unwrap();
+    reference.set_edge_cost(0, LoadT::MAX - 50).unwrap();
+
+    let error = manycore.reallocate_task(4, 4).unwrap_err();
+    assert_eq!(
+        "Routing Error: Load on the South OutputChannel of Core with ID 1 overflowed.",
+        error.to_string()
+    );
+    assert_eq!(reference, manycore);
+    assert_eq!(Some(4), *manycore.cores().list()[5].allocated_task());
+}
@@ -1 +1,3 @@
 pub mod attrs;
+pub mod loads;
+pub mod rng;
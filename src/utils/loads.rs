@@ -0,0 +1,34 @@
+use std::collections::BTreeMap;
+
+use serde::{ser::SerializeSeq, Serialize, Serializer};
+
+use crate::{ChannelIDT, Directions, ElementIDT};
+
+/// A single channel's load, as serialised.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChannelLoadEntry<'a, T> {
+    core_id: ElementIDT,
+    direction: Directions,
+    load: &'a T,
+}
+
+/// Utility function to serialise a per channel load map. Tuple keys cannot be map keys in
+/// most formats, so the map is serialised as a sequence of `{ coreId, direction, load }`
+/// entries in channel order.
+pub(crate) fn serialise_channel_loads<S: Serializer, T: Serialize>(
+    loads: &BTreeMap<ChannelIDT, T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(loads.len()))?;
+
+    for ((core_id, direction), load) in loads {
+        seq.serialize_element(&ChannelLoadEntry {
+            core_id: *core_id,
+            direction: *direction,
+            load,
+        })?;
+    }
+
+    seq.end()
+}
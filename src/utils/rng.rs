@@ -0,0 +1,41 @@
+/// Increment of the SplitMix64 state, also used to space out independent streams.
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Minimal SplitMix64 pseudo random number generator. Deterministic across platforms,
+/// which keeps seeded routing results reproducible.
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Instantiates a generator for one of the independent streams of the given seed.
+    /// The same seed and stream always yield the same sequence, regardless of how many
+    /// other streams were drawn from.
+    pub(crate) fn for_stream(seed: u64, stream: u64) -> Self {
+        let mut base = Self { state: seed };
+
+        Self {
+            state: base
+                .next_u64()
+                .wrapping_add(stream.wrapping_mul(GOLDEN_GAMMA)),
+        }
+    }
+
+    /// Returns the next pseudo random number.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a pseudo random number in `0..bound`. `bound` must be non-zero.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        // Multiply-shift range reduction, the bias is negligible for mesh sized bounds.
+        let wide = u128::from(self.next_u64()) * u128::from(bound);
+
+        u64::try_from(wide >> 64).expect("Reduced value is always below bound.")
+    }
+}